@@ -0,0 +1,249 @@
+//! Incoming webhook gateway: `POST /hooks/<token>` with
+//! `{"text": "...", "username": "CI", "attachment": {"name": "log.txt", "data": "<base64>"}}`
+//! posts message as bot of the webhook.
+//!
+//! Environment: `CHAT_BOT_TOKEN` of bot owning webhooks, `CHAT_SERVER` address
+//! (localhost by default) and `GATEWAY_ADDR` to listen on (0.0.0.0:8080 by default).
+#[path = "../../src-tauri/src/bindings/mod.rs"]
+mod bindings;
+
+use std::{
+    io::Read,
+    sync::{mpsc, Arc, Mutex},
+    time::Duration,
+};
+
+use base64::Engine;
+use bindings::*;
+use spacetimedb_sdk::{DbContext, Status, Table};
+use tiny_http::{Method, Request, Response, Server};
+
+const DB_NAME: &str = "chat";
+/// Max request body, attachment is base64 encoded
+const MAX_BODY: usize = 12 * 1024 * 1024;
+/// Upload chunk, same as desktop client
+const PACKET_SIZE: usize = 2048 * 1024;
+/// Max wait for message to be posted
+const POST_TIMEOUT: Duration = Duration::from_secs(60);
+/// Reducer error prefix of rate limited calls
+const RATE_LIMIT_ERROR: &str = "Too many requests";
+
+#[derive(serde::Deserialize)]
+struct Attachment {
+    name: String,
+    // Base64 encoded content
+    data: String,
+}
+
+#[derive(serde::Deserialize)]
+struct HookBody {
+    #[serde(default)]
+    text: String,
+    username: Option<String>,
+    attachment: Option<Attachment>,
+}
+
+struct Post {
+    text: String,
+    file: Option<(String, Vec<u8>)>,
+}
+
+/// Result of current post, filled by reducer callbacks
+#[derive(Default)]
+struct Posting {
+    // First failed upload reducer
+    error: Option<String>,
+    done: Option<mpsc::Sender<Result<(), String>>>,
+}
+type PostingState = Arc<Mutex<Posting>>;
+
+fn env(name: &str, default: &str) -> String {
+    std::env::var(name).unwrap_or(default.to_string())
+}
+
+fn status_error(status: &Status) -> Option<String> {
+    match status {
+        Status::Committed => None,
+        Status::Failed(err) => Some(err.to_string()),
+        _ => Some("Out of energy".to_string()),
+    }
+}
+
+fn connect(token: String, posting: PostingState) -> DbConnection {
+    let connection = DbConnection::builder()
+        .on_connect(move |ctx, _identity, _token| {
+            ctx.reducers
+                .begin_auth("bot_login".to_string(), String::new(), token.clone())
+                .expect("Spacetime error");
+            ctx.reducers.bot_login(token.clone()).expect("Spacetime error");
+        })
+        .on_disconnect(|_ctx, err| {
+            eprintln!("Disconnected: {:?}", err);
+            std::process::exit(1);
+        })
+        .with_module_name(DB_NAME)
+        .with_uri(format!("http://{}:3000", env("CHAT_SERVER", "localhost")))
+        .build()
+        .expect("Connection error");
+
+    connection.reducers.on_bot_login(|ctx, _token| {
+        if let Some(err) = status_error(&ctx.event.status) {
+            eprintln!("Bot login error: {}", err);
+            std::process::exit(1);
+        }
+
+        ctx.subscription_builder()
+            .on_applied(|ctx| {
+                // Ids are used by admin to revoke single webhook
+                for webhook in ctx.db.webhook().iter() {
+                    println!("Webhook {}: {}", webhook.id, webhook.name);
+                }
+                println!("Logged in, accepting webhooks");
+            })
+            .subscribe(["SELECT * FROM webhook"]);
+    });
+
+    let inner = posting.clone();
+    connection.reducers.on_request_stream(move |ctx, _name, _size| {
+        let mut posting = inner.lock().unwrap();
+        if posting.error.is_none() {
+            posting.error = status_error(&ctx.event.status);
+        }
+    });
+
+    let inner = posting.clone();
+    connection.reducers.on_send_packet(move |ctx, _packet| {
+        let mut posting = inner.lock().unwrap();
+        if posting.error.is_none() {
+            posting.error = status_error(&ctx.event.status);
+        }
+    });
+
+    // Reducers of connection are run in order, message is the last one
+    let inner = posting;
+    connection.reducers.on_send_message(move |ctx, _text, _reply| {
+        let mut posting = inner.lock().unwrap();
+        let result = match (posting.error.take(), status_error(&ctx.event.status)) {
+            (Some(err), _) | (None, Some(err)) => Err(err),
+            (None, None) => Ok(()),
+        };
+
+        if let Some(done) = posting.done.take() {
+            done.send(result).ok();
+        }
+    });
+
+    connection.run_threaded();
+    connection
+}
+
+/// Upload attachment and send message, waits for result
+fn post(connection: &DbConnection, posting: &PostingState, post: Post) -> Result<(), String> {
+    let (done, result) = mpsc::channel();
+    *posting.lock().unwrap() = Posting { error: None, done: Some(done) };
+
+    let reducers = &connection.reducers;
+    let has_file = post.file.is_some();
+    let send = || -> Result<(), spacetimedb_sdk::Error> {
+        if let Some((name, data)) = post.file {
+            reducers.request_stream(name, data.len() as u64)?;
+            for packet in data.chunks(PACKET_SIZE) {
+                reducers.send_packet(packet.to_vec())?;
+            }
+        }
+        reducers.send_message(post.text, None)
+    };
+    let posted = send().map_err(|e| e.to_string()).and_then(|_| {
+        result
+            .recv_timeout(POST_TIMEOUT)
+            .map_err(|_| "Timed out".to_string())?
+    });
+
+    // Unsent upload would be attached to next message
+    if posted.is_err() && has_file {
+        reducers.cancel_stream().ok();
+    }
+    posted
+}
+
+fn parse_body(request: &mut Request) -> Result<Post, String> {
+    let mut body = Vec::new();
+    request
+        .as_reader()
+        .take(MAX_BODY as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|e| e.to_string())?;
+
+    if body.len() > MAX_BODY {
+        return Err(format!("Body must be at most {} Mb", MAX_BODY / 1024 / 1024));
+    }
+
+    let body: HookBody = serde_json::from_slice(&body).map_err(|e| format!("Invalid json: {}", e))?;
+
+    let mut text = body.text.trim().to_string();
+    if let Some(username) = body.username.map(|u| u.replace('*', "")).filter(|u| !u.trim().is_empty()) {
+        text = format!("**{}**: {}", username.trim(), text);
+    }
+
+    let file = match body.attachment {
+        Some(attachment) => {
+            let data = base64::engine::general_purpose::STANDARD
+                .decode(attachment.data)
+                .map_err(|e| format!("Invalid attachment: {}", e))?;
+            Some((attachment.name, data))
+        }
+        None => None,
+    };
+
+    if text.is_empty() && file.is_none() {
+        return Err("Empty message".to_string());
+    }
+
+    Ok(Post { text, file })
+}
+
+/// Token of `/hooks/<token>` url, query and slashes around token are ignored
+fn hook_token(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let token = path.strip_prefix("/hooks/")?.trim_matches('/');
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+fn handle(mut request: Request, connection: &DbConnection, posting: &PostingState) {
+    let token = hook_token(request.url());
+
+    let response = match token {
+        _ if request.method() != &Method::Post => Response::from_string("Method not allowed").with_status_code(405),
+        Some(token) if connection.db.webhook().token().find(&token).is_some() => {
+            match parse_body(&mut request) {
+                Err(e) => Response::from_string(e).with_status_code(400),
+                Ok(message) => match post(connection, posting, message) {
+                    Ok(()) => Response::from_string("").with_status_code(204),
+                    Err(e) if e.starts_with(RATE_LIMIT_ERROR) => Response::from_string(e).with_status_code(429),
+                    Err(e) => Response::from_string(e).with_status_code(502),
+                },
+            }
+        }
+        _ => Response::from_string("Not found").with_status_code(404),
+    };
+
+    if let Err(e) = request.respond(response) {
+        eprintln!("Response error: {}", e);
+    }
+}
+
+fn main() {
+    let token = std::env::var("CHAT_BOT_TOKEN").expect("CHAT_BOT_TOKEN is not set");
+    let addr = env("GATEWAY_ADDR", "0.0.0.0:8080");
+
+    let posting = PostingState::default();
+    let connection = connect(token, posting.clone());
+
+    let server = Server::http(&addr).expect("Listen error");
+    println!("Listening on {}", addr);
+
+    // Requests are handled one by one, connection has single upload stream
+    for request in server.incoming_requests() {
+        handle(request, &connection, &posting);
+    }
+}
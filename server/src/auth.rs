@@ -0,0 +1,67 @@
+use spacetimedb::*;
+
+use crate::{get_creds, rate_limit};
+
+#[table(name=auth_attempt)]
+// Credentials committed by connection before auth reducer call.
+// Failed auth reducer is rolled back, but the attempt keeps its rate limit token,
+// and repeating the same attempt gives the same result
+pub struct AuthAttempt {
+    #[primary_key]
+    identity: Identity,
+    reducer: String,
+    name: String,
+    secret: String,
+}
+
+/// Auth reducers checking secret guessed by caller, with bucket of their attempts
+const ATTEMPT_LIMITS: [(&str, &str); 3] = [
+    ("login", "login"),
+    // Codes are guessed as passwords, share login bucket
+    ("reset_password", "login"),
+    ("bot_login", "bot_login"),
+];
+
+fn get_limit(reducer: &str) -> Option<&'static str> {
+    ATTEMPT_LIMITS.iter().find(|(name, _)| *name == reducer).map(|(_, limit)| *limit)
+}
+
+/// Start auth reducer call, `name` is user name and `secret` is password, reset code or bot token
+#[reducer]
+pub fn begin_auth(ctx: &ReducerContext, reducer: String, name: String, secret: String) -> Result<(), String> {
+    let Some(limit) = get_limit(&reducer) else {
+        return Err(format!("Reducer {} is not auth reducer", reducer));
+    };
+    if get_creds(ctx).is_some() {
+        return Err("Already loginned in".to_string());
+    }
+    rate_limit::check(ctx, limit, None)?;
+
+    let attempt = AuthAttempt { identity: ctx.sender, reducer, name, secret };
+    if ctx.db.auth_attempt().identity().find(ctx.sender).is_some() {
+        ctx.db.auth_attempt().identity().update(attempt);
+    } else {
+        ctx.db.auth_attempt().insert(attempt);
+    }
+
+    Ok(())
+}
+
+/// Check that credentials were committed with `begin_auth`, attempt is used once on success
+pub fn take_attempt(ctx: &ReducerContext, reducer: &str, name: &str, secret: &str) -> Result<(), String> {
+    match ctx.db.auth_attempt().identity().find(ctx.sender) {
+        Some(attempt) if attempt.reducer == reducer && attempt.name == name && attempt.secret == secret => {
+            ctx.db.auth_attempt().identity().delete(ctx.sender);
+            Ok(())
+        }
+        // Attempt is missing when `begin_auth` was limited, caller gets the same delay
+        _ => {
+            rate_limit::check_empty(ctx, get_limit(reducer).unwrap_or(reducer))?;
+            Err(format!("Call begin_auth before {}", reducer))
+        }
+    }
+}
+
+pub fn clear_connection(ctx: &ReducerContext) {
+    ctx.db.auth_attempt().identity().delete(ctx.sender);
+}
@@ -0,0 +1,97 @@
+use std::io::Cursor;
+
+use image::{codecs::jpeg::JpegEncoder, imageops::FilterType, DynamicImage, ImageFormat};
+use spacetimedb::*;
+
+use crate::{bot, get_creds, media, user};
+
+/// Max uploaded image size
+const MAX_UPLOAD_SIZE: usize = 8 * 1024 * 1024;
+const SMALL_SIZE: u32 = 64;
+const LARGE_SIZE: u32 = 256;
+const JPEG_QUALITY: u8 = 85;
+
+#[table(name=avatar, public)]
+// Re-encoded square avatar in two sizes
+pub struct Avatar {
+    #[primary_key]
+    user_id: u32,
+    // Hash of large image, same as `User::avatar_hash`
+    hash: u64,
+    small: Vec<u8>,
+    large: Vec<u8>,
+}
+
+/// FNV-1a, stable between module versions
+fn content_hash(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Center crop to square
+fn crop_square(image: DynamicImage) -> DynamicImage {
+    let side = image.width().min(image.height());
+    let x = (image.width() - side) / 2;
+    let y = (image.height() - side) / 2;
+    image.crop_imm(x, y, side, side)
+}
+
+/// Resize and encode, jpeg for opaque images and png for transparent
+fn encode(image: &DynamicImage, size: u32) -> Result<Vec<u8>, String> {
+    let resized = image.resize_exact(size, size, FilterType::Lanczos3);
+    let mut output = Cursor::new(vec![]);
+
+    let result = if image.color().has_alpha() {
+        resized.to_rgba8().write_to(&mut output, ImageFormat::Png)
+    } else {
+        resized.to_rgb8().write_with_encoder(JpegEncoder::new_with_quality(&mut output, JPEG_QUALITY))
+    };
+
+    result.map_err(|e| format!("Image error: {}", e))?;
+    Ok(output.into_inner())
+}
+
+pub fn remove(ctx: &ReducerContext, user_id: u32) {
+    ctx.db.avatar().user_id().delete(user_id);
+}
+
+#[reducer]
+pub fn set_avatar(ctx: &ReducerContext, data: Vec<u8>) -> Result<(), String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    if data.len() > MAX_UPLOAD_SIZE {
+        return Err(format!("Image must be at most {} Mb", MAX_UPLOAD_SIZE / 1024 / 1024));
+    }
+
+    let format = match image::guess_format(&data) {
+        Ok(format @ (ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP)) => format,
+        _ => return Err("Avatar must be png, jpeg or webp image".to_string()),
+    };
+
+    let image = match media::decode(&data, format) {
+        Ok(image) => crop_square(image),
+        Err(e) => return Err(format!("Image error: {}", e))
+    };
+
+    let large = encode(&image, LARGE_SIZE)?;
+    let small = encode(&image, SMALL_SIZE)?;
+    let hash = content_hash(&large);
+
+    let avatar = Avatar { user_id: creds.user_id, hash, small, large };
+    if ctx.db.avatar().user_id().find(creds.user_id).is_some() {
+        ctx.db.avatar().user_id().update(avatar);
+    } else {
+        ctx.db.avatar().insert(avatar);
+    }
+
+    // Update user avatar
+    let mut user = ctx.db.user().id().find(creds.user_id).unwrap();
+    user.avatar_hash = Some(hash);
+    ctx.db.user().id().update(user);
+
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use spacetimedb::*;
+
+use crate::{auth, close_sessions, command, credentials, get_admin, get_creds, presence, user, Presence, Profile, User, UserCredentials};
+
+/// Conversation id of global chat in scopes
+pub const CHAT: &str = "chat";
+const MIN_TOKEN_LENGTH: usize = 32;
+
+#[derive(SpacetimeType, Clone, PartialEq)]
+pub enum BotScope {
+    // Send, edit and remove own messages in conversation
+    Post(String),
+}
+
+#[table(name=bot_token)]
+// Secret token of bot account, token without scopes is read-only
+pub struct BotToken {
+    #[primary_key]
+    bot_id: u32,
+    #[unique]
+    token: String,
+    scopes: Vec<BotScope>,
+    created_at: Timestamp,
+}
+
+fn get_bot(ctx: &ReducerContext, bot_id: u32) -> Result<User, String> {
+    match ctx.db.user().id().find(bot_id) {
+        Some(user) if user.is_bot && !user.deleted => Ok(user),
+        _ => Err("Bot not found".to_string()),
+    }
+}
+
+/// Logout every connection of bot
+fn close_bot_sessions(ctx: &ReducerContext, bot_id: u32) {
+    if let Some(mut creds) = ctx.db.credentials().user_id().find(bot_id) {
+        close_sessions(ctx, &mut creds, None);
+        ctx.db.credentials().user_id().update(creds);
+    }
+}
+
+/// Bots can act only within scopes of their token, users are not limited
+pub fn check_scope(ctx: &ReducerContext, user_id: u32, scope: BotScope) -> Result<(), String> {
+    if !ctx.db.user().id().find(user_id).is_some_and(|u| u.is_bot) {
+        return Ok(());
+    }
+
+    match ctx.db.bot_token().bot_id().find(user_id) {
+        Some(token) if token.scopes.contains(&scope) => Ok(()),
+        _ => Err("Bot token has no permission for this action".to_string()),
+    }
+}
+
+/// Guard of every user facing reducer changing data, bot without `Post` scope is read-only
+pub fn check_write(ctx: &ReducerContext, user_id: u32) -> Result<(), String> {
+    check_scope(ctx, user_id, BotScope::Post(CHAT.to_string()))
+}
+
+/// Bots are managed by admins, they can't use password reducers
+pub fn check_not_bot(ctx: &ReducerContext, user_id: u32) -> Result<(), String> {
+    if ctx.db.user().id().find(user_id).is_some_and(|u| u.is_bot) {
+        return Err("Not available for bot accounts".to_string());
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn create_bot(ctx: &ReducerContext, name: String, display_name: String) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+
+    if name.len() < 3 {
+        return Err("Name must be at least 3 characters long".to_string());
+    }
+    if ctx.db.user().name().find(name.clone()).is_some() {
+        return Err("User with this name is already exists".to_string());
+    }
+
+    let display_name = match display_name.trim() {
+        "" => name.clone(),
+        display_name => display_name.to_string(),
+    };
+
+    let bot = ctx.db.user().insert(User {
+        id: 0,
+        name,
+        avatar_hash: None,
+        presence: Presence::Offline,
+        last_seen: None,
+        is_admin: false,
+        is_bot: true,
+        deleted: false,
+        profile: Profile::new(display_name),
+    });
+
+    // Password login is disabled for bots
+    ctx.db.credentials().insert(UserCredentials {
+        user_id: bot.id,
+        password: String::new(),
+        connections: vec![],
+        presence: Presence::Online,
+    });
+
+    log::info!("User {} created bot {} ({})", creds.user_id, bot.id, bot.name);
+    Ok(())
+}
+
+/// Set bot token generated by admin client, previous token is revoked
+#[reducer]
+pub fn issue_bot_token(ctx: &ReducerContext, bot_id: u32, token: String, scopes: Vec<BotScope>) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+    get_bot(ctx, bot_id)?;
+
+    if token.len() < MIN_TOKEN_LENGTH {
+        return Err(format!("Token must be at least {} characters long", MIN_TOKEN_LENGTH));
+    }
+    if scopes.iter().any(|BotScope::Post(conversation)| conversation != CHAT) {
+        return Err("Conversation not found".to_string());
+    }
+
+    // Read-only bot can't answer its commands
+    if !scopes.contains(&BotScope::Post(CHAT.to_string())) {
+        command::remove_bot(ctx, bot_id);
+    }
+
+    ctx.db.bot_token().bot_id().delete(bot_id);
+    close_bot_sessions(ctx, bot_id);
+    ctx.db.bot_token().insert(BotToken { bot_id, token, scopes, created_at: ctx.timestamp });
+
+    log::info!("User {} issued token for bot {}", creds.user_id, bot_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn revoke_bot_token(ctx: &ReducerContext, bot_id: u32) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+    get_bot(ctx, bot_id)?;
+
+    if !ctx.db.bot_token().bot_id().delete(bot_id) {
+        return Err("Bot has no token".to_string());
+    }
+    close_bot_sessions(ctx, bot_id);
+    command::remove_bot(ctx, bot_id);
+
+    log::info!("User {} revoked token of bot {}", creds.user_id, bot_id);
+    Ok(())
+}
+
+/// Call after `begin_auth("bot_login", "", token)`
+#[reducer]
+pub fn bot_login(ctx: &ReducerContext, token: String) -> Result<(), String> {
+    if get_creds(ctx).is_some() {
+        return Err("Already loginned in".to_string());
+    }
+    auth::take_attempt(ctx, "bot_login", "", &token)?;
+
+    let Some(bot_token) = ctx.db.bot_token().token().find(token) else {
+        return Err("Invalid bot token".to_string());
+    };
+    let Some(mut creds) = ctx.db.credentials().user_id().find(bot_token.bot_id) else {
+        return Err("Invalid bot token".to_string());
+    };
+    command::check_kicked(ctx, bot_token.bot_id)?;
+
+    creds.connections.push(ctx.sender);
+    ctx.db.credentials().user_id().update(creds);
+    presence::open_session(ctx, bot_token.bot_id);
+
+    Ok(())
+}
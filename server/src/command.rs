@@ -0,0 +1,259 @@
+use std::time::Duration;
+
+use spacetimedb::*;
+
+use crate::{bot, close_sessions, credentials, get_admin, get_config, get_creds, profile::check_length, rate_limit, set_config, user};
+
+/// Commands handled by client and server, bots can't register them
+const BUILTIN: [&str; 6] = ["me", "shrug", "topic", "nick", "remind", "kick"];
+/// Max commands registered by one bot
+const MAX_COMMANDS: usize = 25;
+const MAX_NAME: usize = 32;
+const MAX_HINT: usize = 100;
+const MAX_DESCRIPTION: usize = 200;
+const MAX_TOPIC: usize = 250;
+/// Kicked user can't login again for a while
+const KICK_DURATION: Duration = Duration::from_secs(10 * 60);
+/// Events not handled in time are dropped, e.g. when bot is offline
+const EVENT_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+#[table(name=slash_command, public)]
+// Command contributed by bot, names are global
+pub struct SlashCommand {
+    #[primary_key]
+    name: String,
+    #[index(btree)]
+    bot_id: u32,
+    // Arguments shown in autocomplete, e.g. `<service> [env]`
+    hint: String,
+    description: String,
+}
+
+#[table(name=command_event, public)]
+// Invocation of bot command, removed by bot when handled
+pub struct CommandEvent {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    bot_id: u32,
+    command: String,
+    args: String,
+    sender: u32,
+    // Message replied with command
+    reply: Option<u32>,
+    sent: Timestamp,
+}
+
+#[table(name=command_event_expiry, scheduled(expire_command_event))]
+// Scheduled removal of unhandled command event
+pub struct CommandEventExpiry {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    event_id: u64,
+}
+
+// Events are delivered only to their bot
+#[client_visibility_filter]
+const COMMAND_EVENT_FILTER: Filter = Filter::Sql(
+    "SELECT e.* FROM command_event e JOIN session s ON e.bot_id = s.user_id WHERE s.identity = :sender"
+);
+
+#[table(name=kick)]
+// Temporary login ban of kicked user
+pub struct Kick {
+    #[primary_key]
+    user_id: u32,
+    until: Timestamp,
+}
+
+fn get_bot_creds(ctx: &ReducerContext) -> Result<u32, String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    if !ctx.db.user().id().find(creds.user_id).is_some_and(|u| u.is_bot) {
+        return Err("Only bots can register commands".to_string());
+    }
+
+    Ok(creds.user_id)
+}
+
+/// Commands are stored lowercase without slash
+fn normalize_name(name: &str) -> String {
+    name.trim().trim_start_matches('/').to_lowercase()
+}
+
+#[reducer]
+pub fn register_command(ctx: &ReducerContext, name: String, hint: String, description: String) -> Result<(), String> {
+    let bot_id = get_bot_creds(ctx)?;
+
+    let name = normalize_name(&name);
+    let valid = name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if name.is_empty() || !valid {
+        return Err("Command name must contain only letters, digits, '_' and '-'".to_string());
+    }
+    check_length("Command name", &name, MAX_NAME)?;
+    check_length("Hint", &hint, MAX_HINT)?;
+    check_length("Description", &description, MAX_DESCRIPTION)?;
+
+    if BUILTIN.contains(&name.as_str()) {
+        return Err(format!("/{} is a built-in command", name));
+    }
+
+    let command = SlashCommand { name, bot_id, hint, description };
+    match ctx.db.slash_command().name().find(&command.name) {
+        Some(existing) if existing.bot_id != bot_id => {
+            return Err(format!("/{} is registered by another bot", command.name));
+        }
+        Some(_) => {
+            ctx.db.slash_command().name().update(command);
+        }
+        None => {
+            if ctx.db.slash_command().bot_id().filter(&bot_id).count() >= MAX_COMMANDS {
+                return Err(format!("At most {} commands can be registered", MAX_COMMANDS));
+            }
+            ctx.db.slash_command().insert(command);
+        }
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn unregister_command(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let bot_id = get_bot_creds(ctx)?;
+    let name = normalize_name(&name);
+
+    match ctx.db.slash_command().name().find(&name) {
+        Some(command) if command.bot_id == bot_id => {
+            ctx.db.slash_command().name().delete(&name);
+            Ok(())
+        }
+        _ => Err(format!("/{} is not registered", name)),
+    }
+}
+
+/// Send bot command as event to its bot
+#[reducer]
+pub fn invoke_command(ctx: &ReducerContext, name: String, args: String, reply: Option<u32>) -> Result<(), String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+    rate_limit::check(ctx, "send_message", Some(creds.user_id))?;
+
+    let name = normalize_name(&name);
+    let Some(command) = ctx.db.slash_command().name().find(&name) else {
+        return Err(format!("Unknown command /{}", name));
+    };
+
+    let event = ctx.db.command_event().insert(CommandEvent {
+        id: 0,
+        bot_id: command.bot_id,
+        command: command.name,
+        args: args.trim().to_string(),
+        sender: creds.user_id,
+        reply,
+        sent: ctx.timestamp,
+    });
+    ctx.db.command_event_expiry().insert(CommandEventExpiry {
+        scheduled_id: 0,
+        scheduled_at: (ctx.timestamp + TimeDuration::from(EVENT_TIMEOUT)).into(),
+        event_id: event.id,
+    });
+
+    Ok(())
+}
+
+/// Remove handled event
+#[reducer]
+pub fn ack_command_event(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    match ctx.db.command_event().id().find(id) {
+        Some(event) if event.bot_id == creds.user_id => {
+            ctx.db.command_event().id().delete(id);
+            Ok(())
+        }
+        _ => Err("Event not found".to_string()),
+    }
+}
+
+#[reducer]
+pub fn expire_command_event(ctx: &ReducerContext, expiry: CommandEventExpiry) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer is called only by scheduler".to_string());
+    }
+
+    // Event is already removed if bot handled it
+    ctx.db.command_event().id().delete(expiry.event_id);
+    Ok(())
+}
+
+/// Drop commands and pending events of bot
+pub fn remove_bot(ctx: &ReducerContext, bot_id: u32) {
+    ctx.db.slash_command().bot_id().delete(&bot_id);
+    ctx.db.command_event().bot_id().delete(&bot_id);
+}
+
+#[reducer]
+pub fn set_topic(ctx: &ReducerContext, topic: String) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+
+    let topic = topic.trim().to_string();
+    check_length("Topic", &topic, MAX_TOPIC)?;
+
+    let mut config = get_config(ctx);
+    config.topic = topic;
+    set_config(ctx, config);
+
+    log::info!("User {} changed chat topic", creds.user_id);
+    Ok(())
+}
+
+/// Login of user is rejected until kick expires
+pub fn check_kicked(ctx: &ReducerContext, user_id: u32) -> Result<(), String> {
+    let Some(kick) = ctx.db.kick().user_id().find(user_id) else {
+        return Ok(());
+    };
+
+    if kick.until <= ctx.timestamp {
+        ctx.db.kick().user_id().delete(user_id);
+        return Ok(());
+    }
+
+    Err("You were kicked, try again later".to_string())
+}
+
+/// Disconnect every session of user, login is rejected for `KICK_DURATION`, account is kept
+#[reducer]
+pub fn kick_user(ctx: &ReducerContext, user_id: u32) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+
+    if user_id == creds.user_id {
+        return Err("You can't kick yourself".to_string());
+    }
+
+    let Some(mut target) = ctx.db.credentials().user_id().find(user_id) else {
+        return Err("User not found".to_string());
+    };
+    close_sessions(ctx, &mut target, None);
+    ctx.db.credentials().user_id().update(target);
+
+    let kick = Kick { user_id, until: ctx.timestamp + TimeDuration::from(KICK_DURATION) };
+    if ctx.db.kick().user_id().find(user_id).is_some() {
+        ctx.db.kick().user_id().update(kick);
+    } else {
+        ctx.db.kick().insert(kick);
+    }
+
+    log::warn!("User {} kicked user {}", creds.user_id, user_id);
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use spacetimedb::*;
+
+use crate::{get_admin, insert_file, message, user, Message, Presence, Profile, User};
+
+/// Max messages in one import batch
+const MAX_BATCH: usize = 1000;
+const SOURCES: [&str; 2] = ["slack", "discord"];
+
+#[table(name=import_ref)]
+// Local id of imported foreign object, makes import idempotent
+pub struct ImportRef {
+    // `<source>:<user|message>:<foreign id>`
+    #[primary_key]
+    key: String,
+    id: u32,
+}
+
+#[derive(SpacetimeType)]
+pub struct ImportedFile {
+    name: String,
+    data: Vec<u8>,
+}
+
+#[derive(SpacetimeType)]
+pub struct ImportedMessage {
+    // Foreign message id
+    id: String,
+    // Foreign user id and shown name
+    author: String,
+    author_name: String,
+    sent: Timestamp,
+    text: String,
+    // Foreign id of replied message, imported before
+    reply: Option<String>,
+    file: Option<ImportedFile>,
+}
+
+fn find_ref(ctx: &ReducerContext, key: &str) -> Option<u32> {
+    ctx.db.import_ref().key().find(key.to_string()).map(|r| r.id)
+}
+
+/// Login name of placeholder user, unique among users
+fn placeholder_name(ctx: &ReducerContext, source: &str, author_name: &str) -> String {
+    let base: String = author_name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || "._-".contains(*c))
+        .collect();
+    let base = format!("{}-{}", source, base);
+
+    let mut name = base.clone();
+    let mut suffix = 1;
+    while ctx.db.user().name().find(name.clone()).is_some() {
+        suffix += 1;
+        name = format!("{}-{}", base, suffix);
+    }
+    name
+}
+
+/// Local user of foreign author, placeholder without credentials is created once
+fn author(ctx: &ReducerContext, source: &str, message: &ImportedMessage) -> u32 {
+    let key = format!("{}:user:{}", source, message.author);
+    if let Some(id) = find_ref(ctx, &key) {
+        return id;
+    }
+
+    let display_name = match message.author_name.trim() {
+        "" => message.author.clone(),
+        name => name.to_string(),
+    };
+
+    let user = ctx.db.user().insert(User {
+        id: 0,
+        name: placeholder_name(ctx, source, &display_name),
+        avatar_hash: None,
+        presence: Presence::Offline,
+        last_seen: None,
+        is_admin: false,
+        is_bot: false,
+        deleted: false,
+        profile: Profile::new(display_name),
+    });
+
+    ctx.db.import_ref().insert(ImportRef { key, id: user.id });
+    user.id
+}
+
+/// Insert batch of exported messages with original time, already imported ones are skipped
+#[reducer]
+pub fn import_messages(ctx: &ReducerContext, source: String, messages: Vec<ImportedMessage>) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+
+    if !SOURCES.contains(&source.as_str()) {
+        return Err(format!("Unknown import source {}", source));
+    }
+
+    if messages.len() > MAX_BATCH {
+        return Err(format!("At most {} messages can be imported at once", MAX_BATCH));
+    }
+
+    let mut imported = 0;
+    for message in messages {
+        let key = format!("{}:message:{}", source, message.id);
+        if find_ref(ctx, &key).is_some() {
+            continue;
+        }
+
+        let text = message.text.trim().to_string();
+        if text.is_empty() && message.file.is_none() {
+            continue;
+        }
+
+        let sender = author(ctx, &source, &message);
+        let reply = message
+            .reply
+            .and_then(|reply| find_ref(ctx, &format!("{}:message:{}", source, reply)));
+        let file = message.file.map(|file| insert_file(ctx, file.name, file.data));
+
+        let inserted = ctx.db.message().insert(Message {
+            id: 0,
+            sender,
+            reply,
+            edited: None,
+            sent: message.sent,
+            text,
+            file,
+            mentions: vec![],
+            mention_spans: vec![],
+        });
+
+        ctx.db.import_ref().insert(ImportRef { key, id: inserted.id });
+        imported += 1;
+    }
+
+    log::info!("User {} imported {} messages from {}", creds.user_id, imported, source);
+    Ok(())
+}
@@ -1,5 +1,28 @@
 use spacetimedb::*;
 
+mod auth;
+mod avatar;
+mod bot;
+mod command;
+mod import;
+mod media;
+mod mention;
+mod pin;
+mod presence;
+mod profile;
+mod rate_limit;
+mod read_state;
+mod reminder;
+mod retention;
+mod schedule;
+mod typing;
+mod webhook;
+
+use media::ImageInfo;
+use mention::MentionSpan;
+use presence::Presence;
+use profile::Profile;
+
 #[table(name=credentials)]
 // User private data
 struct UserCredentials {
@@ -7,6 +30,8 @@ struct UserCredentials {
     user_id: u32,
     password: String,
     connections: Vec<Identity>,
+    // Presence chosen by user
+    presence: Presence,
 }
 
 /// Get user credentials linked with identity 
@@ -15,16 +40,120 @@ fn get_creds(ctx: &ReducerContext) -> Option<UserCredentials> {
         .find(|c| c.connections.contains(&ctx.sender))
 }
 
+/// Logout every connection of the user except `keep`
+fn close_sessions(ctx: &ReducerContext, creds: &mut UserCredentials, keep: Option<Identity>) {
+    creds.connections.retain(|i| Some(*i) == keep);
+
+    for identity in presence::user_sessions(ctx, creds.user_id) {
+        if Some(identity) != keep {
+            presence::close_session(ctx, identity);
+        }
+    }
+}
+
+/// Get credentials of logged in admin
+fn get_admin(ctx: &ReducerContext) -> Result<UserCredentials, String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    let user = ctx.db.user().id().find(creds.user_id).unwrap();
+    if !user.is_admin {
+        return Err("Permission denied".to_string());
+    }
+
+    Ok(creds)
+}
+
+fn validate_password(password: &str) -> Result<(), String> {
+    if password.len() < 4 {
+        return Err("Password must be at least 4 characters long".to_string());
+    }
+
+    Ok(())
+}
+
+/// Reset code lifetime
+const RESET_CODE_TTL: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+
+#[table(name=password_reset)]
+// One-time password reset code, issued by admin
+struct PasswordReset {
+    #[primary_key]
+    user_id: u32,
+    code: String,
+    expires: Timestamp,
+}
+
+#[table(name=config, public)]
+// Server-wide settings, single row
+pub struct Config {
+    #[primary_key]
+    id: u32,
+    // Remove messages of deleted accounts instead of anonymizing them
+    purge_deleted_messages: bool,
+    // Remove messages older than this, applied by `retention::apply_retention`
+    retention_days: Option<u32>,
+    // Move expired messages to private archive instead of deleting them
+    archive_expired: bool,
+    // Chat topic shown in header, set by `/topic`
+    topic: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            purge_deleted_messages: false,
+            retention_days: None,
+            archive_expired: false,
+            topic: String::new(),
+        }
+    }
+}
+
+fn get_config(ctx: &ReducerContext) -> Config {
+    ctx.db.config().id().find(0).unwrap_or_default()
+}
+
+fn set_config(ctx: &ReducerContext, config: Config) {
+    if ctx.db.config().id().find(config.id).is_some() {
+        ctx.db.config().id().update(config);
+    } else {
+        ctx.db.config().insert(config);
+    }
+}
+
+#[reducer]
+pub fn set_purge_deleted_messages(ctx: &ReducerContext, value: bool) -> Result<(), String> {
+    get_admin(ctx)?;
+
+    let mut config = get_config(ctx);
+    config.purge_deleted_messages = value;
+    set_config(ctx, config);
+
+    Ok(())
+}
+
 #[table(name=user, public)]
 pub struct User {
     #[primary_key]
     #[auto_inc]
     id: u32,
     is_admin: bool,
-    avatar: Option<Vec<u8>>,
+    // Bot account, logs in with token issued by admin
+    is_bot: bool,
+    // Hash of current avatar, changes when it's replaced
+    avatar_hash: Option<u64>,
     #[unique]
     name: String,
-    online: Vec<Identity>,
+    // Public presence, invisible users are offline
+    presence: Presence,
+    last_seen: Option<Timestamp>,
+    // Account is deleted, row is kept for message history
+    deleted: bool,
+    profile: Profile,
 }
 
 #[table(name=message, public)]
@@ -38,6 +167,9 @@ pub struct Message {
     sent: Timestamp,
     text: String,
     file: Option<FileRef>,
+    // Mentioned users, resolved on send
+    mentions: Vec<u32>,
+    mention_spans: Vec<MentionSpan>,
 }
 
 #[table(name=request, public)]
@@ -66,11 +198,15 @@ pub struct FileRef {
     id: u32,
     name: String,
     size: u64,
+    mime: String,
+    // Dimensions of image file
+    image: Option<ImageInfo>,
 }
 
 #[table(name=file, public)]
 pub struct File {
     #[primary_key]
+    #[auto_inc]
     id: u32,
     name: String,
     data: Vec<u8>
@@ -78,9 +214,13 @@ pub struct File {
 
 #[reducer]
 pub fn request_stream(ctx: &ReducerContext, name: String, size: u64) -> Result<(), String> {
-    if ctx.db.request().sender().find(&ctx.sender).is_some() {
-        return Err("Stream is aleready exists".to_string());
-    }
+    let Some(creds) = get_creds(ctx) else {
+        return Err("Not loginned in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    // Stale upload is left by failed message
+    drop_upload(ctx);
 
     let temp = ctx.db.temp_file().insert(TempFile {
         id: 0,
@@ -101,9 +241,11 @@ pub fn request_stream(ctx: &ReducerContext, name: String, size: u64) -> Result<(
 // Send data pocket
 #[reducer]
 pub fn send_packet(ctx: &ReducerContext, mut pocket: Vec<u8>) -> Result<(), String> {
-    if get_creds(ctx).is_none() {
+    let Some(creds) = get_creds(ctx) else {
         return Err("Not loginned in".to_string());
     };
+    bot::check_write(ctx, creds.user_id)?;
+    rate_limit::check(ctx, "send_packet", Some(creds.user_id))?;
 
     // get stream
     let Some(mut request) = ctx.db.request().sender().find(ctx.sender) else {
@@ -132,40 +274,50 @@ pub fn signup(ctx: &ReducerContext, name: String, password: String) -> Result<()
     if get_creds(ctx).is_some() {
         return Err("Already loginned in".to_string());
     };
+    rate_limit::check(ctx, "signup", None)?;
 
     if name.len() < 3 {
         return Err("Name must be at least 3 characters long".to_string());
     }
-    if password.len() < 4 {
-        return Err("Password must be at least 4 characters long".to_string());
-    } 
+    validate_password(&password)?;
 
     if ctx.db.user().name().find(name.clone()).is_some() {
         return Err("User with this name is already exists".to_string());
     };
 
-    let user = ctx.db.user().insert(User { id: 0, name, avatar: None, online: vec![ctx.sender], is_admin: false });
-    ctx.db.credentials().insert( UserCredentials { user_id: user.id, password, connections: vec![ctx.sender] });
+    let profile = Profile::new(name.clone());
+    let user = ctx.db.user().insert(User { id: 0, name, avatar_hash: None, presence: Presence::Offline, last_seen: None, is_admin: false, is_bot: false, deleted: false, profile });
+    ctx.db.credentials().insert( UserCredentials { user_id: user.id, password, connections: vec![ctx.sender], presence: Presence::Online });
+    presence::open_session(ctx, user.id);
 
     Ok(())
 }
 
+/// Call after `begin_auth("login", name, password)`
 #[reducer]
 pub fn login(ctx: &ReducerContext, name: String, password: String) -> Result<(), String> {
-    let Some(mut user) = ctx.db.user().name().find(name) else {
-        return Err("User with this name is not exists".to_string());
+    if get_creds(ctx).is_some() {
+        return Err("Already loginned in".to_string());
+    }
+    auth::take_attempt(ctx, "login", &name, &password)?;
+
+    // Unknown names and wrong passwords are not told apart
+    let Some(user) = ctx.db.user().name().find(name).filter(|u| !u.is_bot) else {
+        return Err("Invalid name or password".to_string());
+    };
+    // Deleted accounts has no credentials
+    let Some(mut creds) = ctx.db.credentials().user_id().find(user.id) else {
+        return Err("Invalid name or password".to_string());
     };
-    let mut creds = ctx.db.credentials().user_id().find(user.id).unwrap();
 
     if creds.password != password {
-        return Err("Invalid password".to_string());
+        return Err("Invalid name or password".to_string());
     }
+    command::check_kicked(ctx, user.id)?;
 
     creds.connections.push(ctx.sender);
-    user.online.push(ctx.sender);
-
     ctx.db.credentials().user_id().update(creds);
-    ctx.db.user().id().update(user);
+    presence::open_session(ctx, user.id);
 
     Ok(())
 }
@@ -175,63 +327,227 @@ pub fn logout(ctx: &ReducerContext) -> Result<(), String> {
     let Some(mut creds) = get_creds(ctx) else {
         return Err("You are not logged in".to_string());
     };
-    let mut user = ctx.db.user().id().find(creds.user_id).unwrap();
     creds.connections.retain(|i| i != &ctx.sender);
-    user.online.retain(|i| i != &ctx.sender);
-
-    ctx.db.user().id().update(user);
     ctx.db.credentials().user_id().update(creds);
+    presence::close_session(ctx, ctx.sender);
     
     Ok(())
 }
 
 #[reducer]
-pub fn send_message(ctx: &ReducerContext, text: String, reply: Option<u32>) -> Result<(), String> {
-    let text = text.trim().to_string();
-    let Some(creds) = get_creds(ctx) else {
-        return Err("Not loginned in".to_string());
+pub fn change_password(ctx: &ReducerContext, old_password: String, new_password: String) -> Result<(), String> {
+    let Some(mut creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
     };
+    bot::check_not_bot(ctx, creds.user_id)?;
 
-    let file = match ctx.db.request().sender().find(ctx.sender) {
-        Some(request) => {
-            if !request.finished {
-                return Err("Can't send message - file is not uploaded".to_string());
-            }
-            let temp = ctx.db.temp_file().id().find(request.file).unwrap();            
-
-            let file = ctx.db.file().insert(File {
-                id: temp.id,
-                name: temp.name,
-                data: temp.data
-            });
-
-            // Cleanup request and temp file
-            ctx.db.request().sender().delete(request.sender);
-            ctx.db.temp_file().id().delete(temp.id);
+    if creds.password != old_password {
+        return Err("Invalid password".to_string());
+    }
+    validate_password(&new_password)?;
 
-            Some(FileRef { id: file.id, name: file.name, size: file.data.len() as u64 })
-        },
-        None => None
+    // Keep only current session
+    close_sessions(ctx, &mut creds, Some(ctx.sender));
+    creds.password = new_password;
+    ctx.db.credentials().user_id().update(creds);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn issue_reset_code(ctx: &ReducerContext, user_id: u32, code: String) -> Result<(), String> {
+    get_admin(ctx)?;
+
+    if ctx.db.credentials().user_id().find(user_id).is_none() {
+        return Err("User not found".to_string());
+    }
+    bot::check_not_bot(ctx, user_id)?;
+
+    if code.len() < 6 {
+        return Err("Reset code must be at least 6 characters long".to_string());
+    }
+
+    // Previous code is replaced
+    ctx.db.password_reset().user_id().delete(user_id);
+    ctx.db.password_reset().insert(PasswordReset {
+        user_id,
+        code,
+        expires: ctx.timestamp + TimeDuration::from(RESET_CODE_TTL),
+    });
+
+    Ok(())
+}
+
+/// Call after `begin_auth("reset_password", name, code)`
+#[reducer]
+pub fn reset_password(ctx: &ReducerContext, name: String, code: String, password: String) -> Result<(), String> {
+    auth::take_attempt(ctx, "reset_password", &name, &code)?;
+
+    let Some(user) = ctx.db.user().name().find(name) else {
+        return Err("Invalid reset code".to_string());
+    };
+    bot::check_not_bot(ctx, user.id)?;
+
+    let Some(reset) = ctx.db.password_reset().user_id().find(user.id) else {
+        return Err("Invalid reset code".to_string());
+    };
+
+    if reset.code != code {
+        return Err("Invalid reset code".to_string());
+    }
+    if reset.expires < ctx.timestamp {
+        return Err("Reset code is expired".to_string());
+    }
+    validate_password(&password)?;
+    let Some(mut creds) = ctx.db.credentials().user_id().find(user.id) else {
+        return Err("User with this name is not exists".to_string());
+    };
+
+    // Code is single use; every session is closed
+    ctx.db.password_reset().user_id().delete(user.id);
+    close_sessions(ctx, &mut creds, None);
+    creds.password = password;
+    ctx.db.credentials().user_id().update(creds);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn delete_account(ctx: &ReducerContext, password: String) -> Result<(), String> {
+    let Some(mut creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_not_bot(ctx, creds.user_id)?;
+
+    if creds.password != password {
+        return Err("Invalid password".to_string());
+    }
+
+    close_sessions(ctx, &mut creds, None);
+    ctx.db.credentials().user_id().delete(creds.user_id);
+    ctx.db.password_reset().user_id().delete(creds.user_id);
+    read_state::remove(ctx, creds.user_id);
+    avatar::remove(ctx, creds.user_id);
+    schedule::remove_user(ctx, creds.user_id);
+    reminder::remove_user(ctx, creds.user_id);
+
+    // Leave voice room
+    if let Some(mut room) = get_room(ctx, &creds) {
+        room.users.retain(|u| u != &creds.user_id);
+        ctx.db.room().id().update(room);
+    }
+
+    if get_config(ctx).purge_deleted_messages {
+        let messages = ctx.db.message().iter()
+            .filter(|m| m.sender == creds.user_id)
+            .collect::<Vec<_>>();
+
+        for message in messages {
+            purge_message(ctx, &message);
+        }
+    }
+
+    // Tombstone: keep row for message senders, drop personal data
+    let mut user = ctx.db.user().id().find(creds.user_id).unwrap();
+    user.name = format!("deleted#{}", user.id);
+    user.avatar_hash = None;
+    user.last_seen = None;
+    user.is_admin = false;
+    user.deleted = true;
+    user.profile = Profile::new("Deleted user".to_string());
+    ctx.db.user().id().update(user);
+
+    Ok(())
+}
+
+/// Remove upload request of caller with its temp file
+fn drop_upload(ctx: &ReducerContext) {
+    if let Some(request) = ctx.db.request().sender().find(ctx.sender) {
+        ctx.db.request().sender().delete(ctx.sender);
+        ctx.db.temp_file().id().delete(request.file);
+    }
+}
+
+// Cancel upload of caller
+#[reducer]
+pub fn cancel_stream(ctx: &ReducerContext) -> Result<(), String> {
+    if get_creds(ctx).is_none() {
+        return Err("Not loginned in".to_string());
+    }
+
+    drop_upload(ctx);
+    Ok(())
+}
+
+/// Take finished upload of caller, upload request is removed
+fn take_upload(ctx: &ReducerContext) -> Result<Option<TempFile>, String> {
+    let Some(request) = ctx.db.request().sender().find(ctx.sender) else {
+        return Ok(None);
     };
 
-    if text.is_empty() && file.is_none() {
+    if !request.finished {
+        return Err("Can't send message - file is not uploaded".to_string());
+    }
+
+    ctx.db.request().sender().delete(request.sender);
+    Ok(ctx.db.temp_file().id().find(request.file))
+}
+
+/// Store file of message with its preview
+fn insert_file(ctx: &ReducerContext, name: String, data: Vec<u8>) -> FileRef {
+    let file = ctx.db.file().insert(File { id: 0, name, data });
+    let (mime, image) = media::process_file(ctx, file.id, &file.name, &file.data);
+    FileRef { id: file.id, name: file.name, size: file.data.len() as u64, mime, image }
+}
+
+/// Create message of user with uploaded file, used by `send_message` and scheduled messages
+fn post_message(ctx: &ReducerContext, sender: u32, text: String, reply: Option<u32>, upload: Option<TempFile>) -> Result<(), String> {
+    if text.is_empty() && upload.is_none() {
         return Err("Empty message".to_string());
     }
 
+    bot::check_write(ctx, sender)?;
+    let is_admin = ctx.db.user().id().find(sender).unwrap().is_admin;
+    let (mention_spans, mentions) = mention::parse(ctx, &text, sender, is_admin)?;
+
+    let file = match upload {
+        Some(temp) => {
+            // Cleanup temp file
+            ctx.db.temp_file().id().delete(temp.id);
+            Some(insert_file(ctx, temp.name, temp.data))
+        },
+        None => None
+    };
+
     // Create files
     ctx.db.message().insert(Message {
         id: 0,
-        sender: creds.user_id,
+        sender,
         sent: ctx.timestamp,
         reply,
         edited: None,
         text,
-        file
+        file,
+        mentions,
+        mention_spans,
     });
+    typing::clear(ctx, sender);
 
     Ok(())
 }
 
+#[reducer]
+pub fn send_message(ctx: &ReducerContext, text: String, reply: Option<u32>) -> Result<(), String> {
+    let text = text.trim().to_string();
+    let Some(creds) = get_creds(ctx) else {
+        return Err("Not loginned in".to_string());
+    };
+    rate_limit::check(ctx, "send_message", Some(creds.user_id))?;
+
+    let upload = take_upload(ctx)?;
+    post_message(ctx, creds.user_id, text, reply, upload)
+}
+
 #[reducer]
 pub fn remove_message(ctx: &ReducerContext, id: u32) -> Result<(), String> {
     let Some(creds) = get_creds(ctx) else {
@@ -245,16 +561,23 @@ pub fn remove_message(ctx: &ReducerContext, id: u32) -> Result<(), String> {
     if !(user.id == message.sender || user.is_admin) {
         return Err("Permission denied".to_string());
     }
+    bot::check_write(ctx, user.id)?;
 
-    // Remove attached file
-    if let Some(file_ref) = message.file {
-        ctx.db.file().id().delete(file_ref.id);
-    }
-    
-    ctx.db.message().id().delete(id);
+    purge_message(ctx, &message);
     Ok(())
 }
 
+/// Delete message with attached file and everything referencing it
+fn purge_message(ctx: &ReducerContext, message: &Message) {
+    if let Some(file_ref) = &message.file {
+        media::remove_file(ctx, file_ref.id);
+    }
+
+    pin::remove(ctx, message.id);
+    reminder::remove_message(ctx, message.id);
+    ctx.db.message().id().delete(message.id);
+}
+
 #[reducer]
 pub fn edit_message(ctx: &ReducerContext, id: u32, text: String) -> Result<(), String> {
     let text = text.trim().to_string();
@@ -270,12 +593,16 @@ pub fn edit_message(ctx: &ReducerContext, id: u32, text: String) -> Result<(), S
     if !(user.id == message.sender) {
         return Err("Permission denied".to_string());
     }
+    bot::check_write(ctx, user.id)?;
 
     if text.is_empty() && message.file.is_none() {
         return Err("Empty message".to_string());
     }
 
     if message.text != text {
+        let (mention_spans, mentions) = mention::parse(ctx, &text, user.id, user.is_admin)?;
+        message.mentions = mentions;
+        message.mention_spans = mention_spans;
         message.text = text;
         message.edited = Some(ctx.timestamp);
     }
@@ -293,30 +620,21 @@ pub fn upload_file(ctx: &ReducerContext, name: String, data: Vec<u8>) -> Result<
     Ok(())
 }
 
-pub fn update_online(ctx: &ReducerContext, value: bool) {
-    if let Some(creds) = get_creds(ctx) {
-        let mut user = ctx.db.user().id().find(creds.user_id).unwrap();
-        user.online.retain(|v| v != &ctx.sender);
-        if value { user.online.push(ctx.sender) };
-
-        ctx.db.user().id().update(user);
-    }
-}
-
 #[reducer(client_connected)]
 pub fn client_connected(ctx: &ReducerContext) {
-    update_online(ctx, true);
+    if let Some(creds) = get_creds(ctx) {
+        presence::open_session(ctx, creds.user_id);
+    }
 }
 
 #[reducer(client_disconnected)]
 pub fn client_disconnected(ctx: &ReducerContext) {
-    update_online(ctx, false);
+    presence::close_session(ctx, ctx.sender);
+    rate_limit::clear_connection(ctx);
+    auth::clear_connection(ctx);
 
     // Close request if exists
-    if let Some(request) = ctx.db.request().sender().find(ctx.sender) {
-        ctx.db.request().sender().delete(ctx.sender);
-        ctx.db.temp_file().id().delete(request.file);
-    }
+    drop_upload(ctx);
 }
 
 #[table(name=voice_packet, public)]
@@ -345,6 +663,7 @@ pub fn send_voice_packet(ctx: &ReducerContext, data: Vec<f32>) -> Result<(), Str
     let Some(creds) = get_creds(ctx) else {
         return Err("You are not logged in".to_string());
     };
+    bot::check_write(ctx, creds.user_id)?;
 
     let Some(room) = get_room(ctx, &creds) else {
         return Err("User not in a voice room".to_string());
@@ -356,28 +675,5 @@ pub fn send_voice_packet(ctx: &ReducerContext, data: Vec<f32>) -> Result<(), Str
         data
     });
 
-    Ok(())
-}
-
-#[reducer]
-pub fn set_avatar(ctx: &ReducerContext, data: Vec<u8>) -> Result<(), String> {
-    let Some(creds) = get_creds(ctx) else {
-        return Err("You are not logged in".to_string());
-    };
-
-    let image = match image::load_from_memory(&data) {
-        Ok(image) => image,
-        Err(e) => return Err(format!("Image error: {}", e))
-    };
-
-    if image.width() != image.height() {
-        return Err(format!("Image size error w{} not equals h{}", image.width(), image.height()));
-    }
-
-    // Update user avatar
-    let mut user = ctx.db.user().id().find(creds.user_id).unwrap();
-    user.avatar = Some(data);
-    ctx.db.user().id().update(user);
-
     Ok(())
 }
\ No newline at end of file
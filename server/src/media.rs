@@ -0,0 +1,94 @@
+use std::io::Cursor;
+
+use image::{imageops::FilterType, DynamicImage, ImageFormat, ImageReader, Limits};
+use spacetimedb::*;
+
+use crate::file;
+
+/// Max thumbnail side in pixels
+const THUMBNAIL_SIZE: u32 = 256;
+/// Decoder limits, small compressed images can expand to huge pixel buffers
+const MAX_IMAGE_SIDE: u32 = 8192;
+const MAX_IMAGE_ALLOC: u64 = 64 * 1024 * 1024;
+
+/// Types of common non-image attachments by extension
+const MIME_TYPES: [(&str, &str); 12] = [
+    ("txt", "text/plain"),
+    ("md", "text/markdown"),
+    ("json", "application/json"),
+    ("pdf", "application/pdf"),
+    ("zip", "application/zip"),
+    ("mp3", "audio/mpeg"),
+    ("ogg", "audio/ogg"),
+    ("wav", "audio/wav"),
+    ("mp4", "video/mp4"),
+    ("webm", "video/webm"),
+    ("gif", "image/gif"),
+    ("svg", "image/svg+xml"),
+];
+
+#[derive(SpacetimeType, Clone)]
+pub struct ImageInfo {
+    width: u32,
+    height: u32,
+}
+
+#[table(name=thumbnail, public)]
+// Small jpeg preview of image file
+pub struct Thumbnail {
+    #[primary_key]
+    file_id: u32,
+    data: Vec<u8>,
+}
+
+fn mime_from_name(name: &str) -> &'static str {
+    let extension = name.rsplit_once('.')
+        .map(|(_, ext)| ext.to_lowercase())
+        .unwrap_or_default();
+
+    MIME_TYPES.iter()
+        .find(|(ext, _)| *ext == extension)
+        .map_or("application/octet-stream", |(_, mime)| *mime)
+}
+
+/// Decode image within size and memory limits
+pub fn decode(data: &[u8], format: ImageFormat) -> Result<DynamicImage, String> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_IMAGE_SIDE);
+    limits.max_image_height = Some(MAX_IMAGE_SIDE);
+    limits.max_alloc = Some(MAX_IMAGE_ALLOC);
+
+    let mut reader = ImageReader::with_format(Cursor::new(data), format);
+    reader.limits(limits);
+    reader.decode().map_err(|e| e.to_string())
+}
+
+/// Detect file type, for images store dimensions and create thumbnail
+pub fn process_file(ctx: &ReducerContext, id: u32, name: &str, data: &[u8]) -> (String, Option<ImageInfo>) {
+    let format = image::guess_format(data).ok();
+    let image = format.and_then(|format| decode(data, format).ok());
+
+    let (Some(format), Some(image)) = (format, image) else {
+        return (mime_from_name(name).to_string(), None);
+    };
+
+    // Small images are not upscaled
+    let preview = if image.width() <= THUMBNAIL_SIZE && image.height() <= THUMBNAIL_SIZE {
+        image.to_rgb8()
+    } else {
+        image.resize(THUMBNAIL_SIZE, THUMBNAIL_SIZE, FilterType::Triangle).to_rgb8()
+    };
+    let mut thumbnail = Cursor::new(vec![]);
+    if preview.write_to(&mut thumbnail, ImageFormat::Jpeg).is_ok() {
+        ctx.db.thumbnail().insert(Thumbnail { file_id: id, data: thumbnail.into_inner() });
+    }
+
+    let info = ImageInfo { width: image.width(), height: image.height() };
+    (format.to_mime_type().to_string(), Some(info))
+}
+
+/// Remove file with its thumbnail
+pub fn remove_file(ctx: &ReducerContext, id: u32) {
+    ctx.db.file().id().delete(id);
+    ctx.db.thumbnail().file_id().delete(id);
+}
@@ -0,0 +1,123 @@
+use spacetimedb::*;
+
+use crate::{presence::Presence, user};
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum MentionKind {
+    User(u32),
+    // Users online at send time
+    Here,
+    // All users, admin only
+    Everyone,
+}
+
+#[derive(SpacetimeType, Clone)]
+// Mention token in message text, byte offsets
+pub struct MentionSpan {
+    kind: MentionKind,
+    start: u32,
+    end: u32,
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || "_-.".contains(c)
+}
+
+/// Find `@token` words, returns byte range without `@`
+fn tokens(text: &str) -> Vec<(usize, usize)> {
+    let mut result = vec![];
+    let mut prev = None;
+
+    for (i, c) in text.char_indices() {
+        if c == '@' && !prev.is_some_and(is_name_char) {
+            let start = i + 1;
+            let len = text[start..]
+                .char_indices()
+                .find(|(_, c)| !is_name_char(*c))
+                .map_or(text.len() - start, |(n, _)| n);
+
+            // Trailing dot ends sentence
+            let token = text[start..start + len].trim_end_matches('.');
+            if !token.is_empty() {
+                result.push((start, start + token.len()));
+            }
+        }
+        prev = Some(c);
+    }
+
+    result
+}
+
+/// Resolve mentions of message text: spans and mentioned user ids without sender
+pub fn parse(ctx: &ReducerContext, text: &str, sender: u32, is_admin: bool) -> Result<(Vec<MentionSpan>, Vec<u32>), String> {
+    let mut spans = vec![];
+    let mut users = vec![];
+
+    for (start, end) in tokens(text) {
+        let kind = match &text[start..end] {
+            "here" => MentionKind::Here,
+            "everyone" if !is_admin => {
+                return Err("Permission denied: @everyone".to_string());
+            }
+            "everyone" => MentionKind::Everyone,
+            name => match ctx.db.user().name().find(name.to_string()) {
+                Some(user) if !user.deleted => MentionKind::User(user.id),
+                _ => continue,
+            },
+        };
+
+        match kind {
+            MentionKind::User(id) => users.push(id),
+            MentionKind::Here => users.extend(ctx.db.user().iter()
+                .filter(|u| u.presence != Presence::Offline)
+                .map(|u| u.id)),
+            MentionKind::Everyone => users.extend(ctx.db.user().iter()
+                .filter(|u| !u.deleted)
+                .map(|u| u.id)),
+        }
+
+        // Span includes `@`
+        spans.push(MentionSpan { kind, start: start as u32 - 1, end: end as u32 });
+    }
+
+    users.retain(|id| *id != sender);
+    users.sort();
+    users.dedup();
+
+    Ok((spans, users))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tokens;
+
+    fn names(text: &str) -> Vec<&str> {
+        tokens(text).into_iter().map(|(start, end)| &text[start..end]).collect()
+    }
+
+    #[test]
+    fn finds_mentions() {
+        assert_eq!(names("@alice hi @bob"), ["alice", "bob"]);
+        assert_eq!(names("ping @here, @everyone!"), ["here", "everyone"]);
+        assert_eq!(names("(@first.last)"), ["first.last"]);
+    }
+
+    #[test]
+    fn trims_trailing_dot() {
+        assert_eq!(names("thanks @alice."), ["alice"]);
+        assert_eq!(names("@..."), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn skips_emails_and_bare_at() {
+        assert_eq!(names("mail user@example.com"), Vec::<&str>::new());
+        assert_eq!(names("@ alone @"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn keeps_byte_offsets_with_unicode() {
+        let text = "привет @Мария";
+        let (start, end) = tokens(text)[0];
+        assert_eq!(&text[start - 1..end], "@Мария");
+    }
+}
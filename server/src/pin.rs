@@ -0,0 +1,45 @@
+use spacetimedb::*;
+
+use crate::{get_admin, message};
+
+#[table(name=pin, public)]
+// Pinned message, there is only one conversation
+pub struct Pin {
+    #[primary_key]
+    message_id: u32,
+    pinned_by: u32,
+    pinned_at: Timestamp,
+}
+
+/// Unpin removed message
+pub fn remove(ctx: &ReducerContext, message_id: u32) {
+    ctx.db.pin().message_id().delete(message_id);
+}
+
+#[reducer]
+pub fn pin_message(ctx: &ReducerContext, message_id: u32) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+
+    if ctx.db.message().id().find(message_id).is_none() {
+        return Err("Message not found".to_string());
+    }
+
+    if ctx.db.pin().message_id().find(message_id).is_some() {
+        return Err("Message is already pinned".to_string());
+    }
+
+    ctx.db.pin().insert(Pin { message_id, pinned_by: creds.user_id, pinned_at: ctx.timestamp });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn unpin_message(ctx: &ReducerContext, message_id: u32) -> Result<(), String> {
+    get_admin(ctx)?;
+
+    if !ctx.db.pin().message_id().delete(message_id) {
+        return Err("Message is not pinned".to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use spacetimedb::*;
+
+use crate::{bot, credentials, get_creds, user};
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum Presence {
+    Offline,
+    Online,
+    Idle,
+    DoNotDisturb,
+    // Shown to other users as offline
+    Invisible,
+}
+
+#[table(name=session, public)]
+// Connected and logged in identity
+pub struct Session {
+    #[primary_key]
+    identity: Identity,
+    #[index(btree)]
+    user_id: u32,
+}
+
+// Identities are not exposed to other users
+#[client_visibility_filter]
+const SESSION_FILTER: Filter = Filter::Sql("SELECT * FROM session WHERE identity = :sender");
+
+/// Update public presence from user sessions and chosen state
+pub fn update_presence(ctx: &ReducerContext, user_id: u32) {
+    let Some(mut user) = ctx.db.user().id().find(user_id) else {
+        return;
+    };
+
+    let chosen = ctx.db.credentials().user_id().find(user_id)
+        .map(|c| c.presence)
+        .unwrap_or(Presence::Offline);
+    let online = ctx.db.session().user_id().filter(&user_id).next().is_some();
+
+    let presence = match chosen {
+        _ if !online => Presence::Offline,
+        Presence::Invisible => Presence::Offline,
+        presence => presence,
+    };
+
+    if user.presence != presence {
+        user.presence = presence;
+        ctx.db.user().id().update(user);
+    }
+}
+
+pub fn open_session(ctx: &ReducerContext, user_id: u32) {
+    if ctx.db.session().identity().find(ctx.sender).is_none() {
+        ctx.db.session().insert(Session { identity: ctx.sender, user_id });
+    }
+
+    update_presence(ctx, user_id);
+}
+
+/// Close session of identity, invisible user keeps old `last_seen`
+pub fn close_session(ctx: &ReducerContext, identity: Identity) {
+    let Some(session) = ctx.db.session().identity().find(identity) else {
+        return;
+    };
+    ctx.db.session().identity().delete(identity);
+
+    let mut user = ctx.db.user().id().find(session.user_id).unwrap();
+    if user.presence != Presence::Offline {
+        user.last_seen = Some(ctx.timestamp);
+        ctx.db.user().id().update(user);
+    }
+
+    update_presence(ctx, session.user_id);
+}
+
+/// Get identities of user sessions
+pub fn user_sessions(ctx: &ReducerContext, user_id: u32) -> Vec<Identity> {
+    ctx.db.session().user_id().filter(&user_id)
+        .map(|s| s.identity)
+        .collect()
+}
+
+#[reducer]
+pub fn set_presence(ctx: &ReducerContext, presence: Presence) -> Result<(), String> {
+    let Some(mut creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    if presence == Presence::Offline {
+        return Err("Use invisible presence to appear offline".to_string());
+    }
+
+    let user_id = creds.user_id;
+    creds.presence = presence;
+    ctx.db.credentials().user_id().update(creds);
+    update_presence(ctx, user_id);
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use spacetimedb::*;
+
+use crate::{bot, get_creds, user};
+
+const MAX_DISPLAY_NAME: usize = 32;
+const MAX_STATUS_TEXT: usize = 128;
+const MAX_STATUS_EMOJI: usize = 8;
+const MAX_BIO: usize = 300;
+const MAX_PRONOUNS: usize = 32;
+const MAX_TIMEZONE: usize = 64;
+
+#[derive(SpacetimeType, Clone)]
+pub struct UserStatus {
+    text: String,
+    emoji: Option<String>,
+    // Status is hidden after expiry
+    expires: Option<Timestamp>,
+}
+
+#[derive(SpacetimeType, Clone)]
+pub struct Profile {
+    // Non-unique name shown instead of login name
+    display_name: String,
+    status: Option<UserStatus>,
+    bio: String,
+    pronouns: String,
+    // IANA timezone name, e.g. "Europe/Berlin"
+    timezone: String,
+}
+
+impl Profile {
+    pub fn new(display_name: String) -> Self {
+        Self {
+            display_name,
+            status: None,
+            bio: String::new(),
+            pronouns: String::new(),
+            timezone: String::new(),
+        }
+    }
+}
+
+pub(crate) fn check_length(field: &str, value: &str, max: usize) -> Result<(), String> {
+    if value.chars().count() > max {
+        return Err(format!("{} must be at most {} characters long", field, max));
+    }
+
+    Ok(())
+}
+
+fn validate_timezone(timezone: &str) -> Result<(), String> {
+    check_length("Timezone", timezone, MAX_TIMEZONE)?;
+
+    let valid = timezone
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "/_+-".contains(c));
+
+    if !valid {
+        return Err(format!("Invalid timezone {}", timezone));
+    }
+
+    Ok(())
+}
+
+fn validate(ctx: &ReducerContext, profile: &mut Profile) -> Result<(), String> {
+    profile.display_name = profile.display_name.trim().to_string();
+    profile.bio = profile.bio.trim().to_string();
+    profile.pronouns = profile.pronouns.trim().to_string();
+    profile.timezone = profile.timezone.trim().to_string();
+
+    if profile.display_name.is_empty() {
+        return Err("Display name is empty".to_string());
+    }
+    check_length("Display name", &profile.display_name, MAX_DISPLAY_NAME)?;
+    check_length("Bio", &profile.bio, MAX_BIO)?;
+    check_length("Pronouns", &profile.pronouns, MAX_PRONOUNS)?;
+    validate_timezone(&profile.timezone)?;
+
+    if let Some(status) = &mut profile.status {
+        status.text = status.text.trim().to_string();
+        check_length("Status", &status.text, MAX_STATUS_TEXT)?;
+
+        if let Some(emoji) = &status.emoji {
+            check_length("Status emoji", emoji, MAX_STATUS_EMOJI)?;
+        }
+
+        if status.expires.is_some_and(|expires| expires <= ctx.timestamp) {
+            return Err("Status expiry is in the past".to_string());
+        }
+    }
+
+    // Empty status is cleared
+    if profile.status.as_ref().is_some_and(|s| s.text.is_empty() && s.emoji.is_none()) {
+        profile.status = None;
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn update_profile(ctx: &ReducerContext, mut profile: Profile) -> Result<(), String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    validate(ctx, &mut profile)?;
+
+    let mut user = ctx.db.user().id().find(creds.user_id).unwrap();
+    user.profile = profile;
+    ctx.db.user().id().update(user);
+
+    Ok(())
+}
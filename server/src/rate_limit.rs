@@ -0,0 +1,171 @@
+use spacetimedb::*;
+
+use crate::get_admin;
+
+/// Error prefix, client parses delay after it
+const RATE_LIMIT_ERROR: &str = "Too many requests, retry after";
+
+/// Limits used when no admin override exists: (reducer, capacity, refill_ms)
+const DEFAULT_LIMITS: [(&str, u32, u64); 6] = [
+    ("send_message", 10, 1_000),
+    ("send_packet", 64, 100),
+    ("signup", 3, 60_000),
+    ("login", 5, 10_000),
+    ("bot_login", 20, 3_000),
+    ("set_typing", 3, 1_000),
+];
+/// Buckets of these reducers are kept on disconnect until refilled,
+/// so reconnecting doesn't restore tokens of failed attempts
+const AUTH_REDUCERS: [&str; 3] = ["signup", "login", "bot_login"];
+
+#[table(name=rate_limit, public)]
+// Token bucket settings of limited reducer
+pub struct RateLimit {
+    #[primary_key]
+    reducer: String,
+    // Bucket size, max calls in burst
+    capacity: u32,
+    // Time to restore one token
+    refill_ms: u64,
+}
+
+#[table(name=rate_bucket)]
+// Token bucket state of identity or user
+struct RateBucket {
+    #[primary_key]
+    key: String,
+    tokens: f64,
+    updated: Timestamp,
+}
+
+fn get_limit(ctx: &ReducerContext, reducer: &str) -> Option<RateLimit> {
+    if let Some(limit) = ctx.db.rate_limit().reducer().find(reducer.to_string()) {
+        return Some(limit);
+    }
+
+    DEFAULT_LIMITS
+        .iter()
+        .find(|(name, ..)| *name == reducer)
+        .map(|(name, capacity, refill_ms)| RateLimit {
+            reducer: name.to_string(),
+            capacity: *capacity,
+            refill_ms: *refill_ms,
+        })
+}
+
+/// Tokens of bucket refilled up to now
+fn current_tokens(ctx: &ReducerContext, bucket: Option<&RateBucket>, limit: &RateLimit) -> f64 {
+    let capacity = limit.capacity as f64;
+
+    match bucket {
+        Some(bucket) => {
+            let elapsed = ctx.timestamp.duration_since(bucket.updated).unwrap_or_default();
+            (bucket.tokens + elapsed.as_millis() as f64 / limit.refill_ms as f64).min(capacity)
+        }
+        None => capacity,
+    }
+}
+
+fn limited_error(tokens: f64, limit: &RateLimit) -> String {
+    let delay = ((1.0 - tokens) * limit.refill_ms as f64).ceil() as u64;
+    format!("{} {} ms", RATE_LIMIT_ERROR, delay)
+}
+
+/// Take token from bucket, returns retry error if bucket is empty
+fn take_token(ctx: &ReducerContext, key: String, limit: &RateLimit) -> Result<(), String> {
+    let bucket = ctx.db.rate_bucket().key().find(&key);
+    let tokens = current_tokens(ctx, bucket.as_ref(), limit);
+
+    if tokens < 1.0 {
+        return Err(limited_error(tokens, limit));
+    }
+
+    let state = RateBucket { key, tokens: tokens - 1.0, updated: ctx.timestamp };
+    if bucket.is_some() {
+        ctx.db.rate_bucket().key().update(state);
+    } else {
+        ctx.db.rate_bucket().insert(state);
+    }
+
+    Ok(())
+}
+
+fn check_keys(ctx: &ReducerContext, reducer: &str, keys: Vec<String>) -> Result<(), String> {
+    let Some(limit) = get_limit(ctx, reducer) else {
+        return Ok(());
+    };
+
+    for key in keys {
+        take_token(ctx, key, &limit)?;
+    }
+
+    Ok(())
+}
+
+/// Check reducer limit for caller identity and user.
+/// Failed reducer is rolled back with its tokens, so only successful calls are counted,
+/// auth reducers are counted by `auth::begin_auth` before the check
+pub fn check(ctx: &ReducerContext, reducer: &str, user_id: Option<u32>) -> Result<(), String> {
+    let mut keys = vec![format!("{}/{}", reducer, ctx.sender)];
+    if let Some(id) = user_id {
+        keys.push(format!("{}/user/{}", reducer, id));
+    }
+
+    check_keys(ctx, reducer, keys)
+}
+
+/// Fail with retry delay if caller has no tokens left, no token is taken
+pub fn check_empty(ctx: &ReducerContext, reducer: &str) -> Result<(), String> {
+    let Some(limit) = get_limit(ctx, reducer) else {
+        return Ok(());
+    };
+
+    let bucket = ctx.db.rate_bucket().key().find(format!("{}/{}", reducer, ctx.sender));
+    let tokens = current_tokens(ctx, bucket.as_ref(), &limit);
+    if tokens < 1.0 {
+        return Err(limited_error(tokens, &limit));
+    }
+
+    Ok(())
+}
+
+/// Remove buckets of disconnected identity, auth buckets are kept until refilled
+pub fn clear_connection(ctx: &ReducerContext) {
+    for (reducer, ..) in DEFAULT_LIMITS {
+        let key = format!("{}/{}", reducer, ctx.sender);
+
+        if AUTH_REDUCERS.contains(&reducer) {
+            let Some(limit) = get_limit(ctx, reducer) else {
+                continue;
+            };
+            let bucket = ctx.db.rate_bucket().key().find(&key);
+            if current_tokens(ctx, bucket.as_ref(), &limit) < limit.capacity as f64 {
+                continue;
+            }
+        }
+
+        ctx.db.rate_bucket().key().delete(key);
+    }
+}
+
+#[reducer]
+pub fn set_rate_limit(ctx: &ReducerContext, reducer: String, capacity: u32, refill_ms: u64) -> Result<(), String> {
+    get_admin(ctx)?;
+
+    if !DEFAULT_LIMITS.iter().any(|(name, ..)| *name == reducer) {
+        return Err(format!("Reducer {} is not limited", reducer));
+    }
+
+    if capacity == 0 || refill_ms == 0 {
+        return Err("Capacity and refill time must be positive".to_string());
+    }
+
+    let limit = RateLimit { reducer, capacity, refill_ms };
+    if ctx.db.rate_limit().reducer().find(&limit.reducer).is_some() {
+        ctx.db.rate_limit().reducer().update(limit);
+    } else {
+        ctx.db.rate_limit().insert(limit);
+    }
+
+    Ok(())
+}
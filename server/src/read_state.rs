@@ -0,0 +1,81 @@
+use spacetimedb::*;
+
+use crate::{bot, get_creds, message};
+
+#[table(name=read_state, public)]
+// Last read message of user, there is only one conversation
+pub struct ReadState {
+    #[primary_key]
+    user_id: u32,
+    last_read: u32,
+    // Send time of last read message, messages are compared with it, not by id:
+    // imported history gets new ids with old send times
+    read_until: Timestamp,
+    // Read marker is shown to other users as read receipts
+    share_receipts: bool,
+}
+
+// Read state is visible only to devices of its owner
+#[client_visibility_filter]
+const READ_STATE_FILTER: Filter = Filter::Sql(
+    "SELECT r.* FROM read_state r JOIN session s ON r.user_id = s.user_id WHERE s.identity = :sender"
+);
+
+// Shared read states are visible to everyone as read receipts
+#[client_visibility_filter]
+const READ_RECEIPT_FILTER: Filter = Filter::Sql("SELECT * FROM read_state WHERE share_receipts = true");
+
+fn upsert(ctx: &ReducerContext, state: ReadState) {
+    if ctx.db.read_state().user_id().find(state.user_id).is_some() {
+        ctx.db.read_state().user_id().update(state);
+    } else {
+        ctx.db.read_state().insert(state);
+    }
+}
+
+pub fn remove(ctx: &ReducerContext, user_id: u32) {
+    ctx.db.read_state().user_id().delete(user_id);
+}
+
+#[reducer]
+pub fn mark_read(ctx: &ReducerContext, message_id: u32) -> Result<(), String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    let Some(message) = ctx.db.message().id().find(message_id) else {
+        return Err("Message is not exists".to_string());
+    };
+
+    // Marker is only moved forward, other device could read further
+    let share_receipts = match ctx.db.read_state().user_id().find(creds.user_id) {
+        Some(old) if message.sent <= old.read_until => return Ok(()),
+        Some(old) => old.share_receipts,
+        None => true,
+    };
+
+    upsert(ctx, ReadState { user_id: creds.user_id, last_read: message_id, read_until: message.sent, share_receipts });
+    Ok(())
+}
+
+#[reducer]
+pub fn set_read_receipts(ctx: &ReducerContext, enabled: bool) -> Result<(), String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    let state = match ctx.db.read_state().user_id().find(creds.user_id) {
+        Some(state) => ReadState { share_receipts: enabled, ..state },
+        None => ReadState {
+            user_id: creds.user_id,
+            last_read: 0,
+            read_until: Timestamp::from_micros_since_unix_epoch(0),
+            share_receipts: enabled,
+        },
+    };
+
+    upsert(ctx, state);
+    Ok(())
+}
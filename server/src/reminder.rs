@@ -0,0 +1,148 @@
+use spacetimedb::*;
+
+use crate::{bot, get_creds, message};
+
+/// Max pending reminders of user
+const MAX_REMINDERS: usize = 100;
+
+#[table(name=reminder, public, scheduled(fire_reminder))]
+// Private reminder about message, visible only to its owner
+pub struct Reminder {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    #[index(btree)]
+    user_id: u32,
+    #[index(btree)]
+    message_id: u32,
+}
+
+#[table(name=notification, public)]
+// Fired reminder, kept until dismissed or snoozed
+pub struct Notification {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    user_id: u32,
+    #[index(btree)]
+    message_id: u32,
+    created_at: Timestamp,
+}
+
+#[client_visibility_filter]
+const REMINDER_FILTER: Filter = Filter::Sql(
+    "SELECT r.* FROM reminder r JOIN session s ON r.user_id = s.user_id WHERE s.identity = :sender"
+);
+
+#[client_visibility_filter]
+const NOTIFICATION_FILTER: Filter = Filter::Sql(
+    "SELECT n.* FROM notification n JOIN session s ON n.user_id = s.user_id WHERE s.identity = :sender"
+);
+
+fn insert_reminder(ctx: &ReducerContext, user_id: u32, message_id: u32, at: Timestamp) -> Result<(), String> {
+    if at <= ctx.timestamp {
+        return Err("Reminder time is in the past".to_string());
+    }
+
+    if ctx.db.reminder().user_id().filter(&user_id).count() >= MAX_REMINDERS {
+        return Err(format!("At most {} reminders can be set", MAX_REMINDERS));
+    }
+
+    ctx.db.reminder().insert(Reminder {
+        scheduled_id: 0,
+        scheduled_at: at.into(),
+        user_id,
+        message_id,
+    });
+
+    Ok(())
+}
+
+fn get_own_notification(ctx: &ReducerContext, id: u64) -> Result<Notification, String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    match ctx.db.notification().id().find(id) {
+        Some(notification) if notification.user_id == creds.user_id => Ok(notification),
+        _ => Err("Notification not found".to_string()),
+    }
+}
+
+/// Drop reminders about removed message
+pub fn remove_message(ctx: &ReducerContext, message_id: u32) {
+    ctx.db.reminder().message_id().delete(&message_id);
+    ctx.db.notification().message_id().delete(&message_id);
+}
+
+/// Drop reminders of deleted user
+pub fn remove_user(ctx: &ReducerContext, user_id: u32) {
+    ctx.db.reminder().user_id().delete(&user_id);
+    ctx.db.notification().user_id().delete(&user_id);
+}
+
+#[reducer]
+pub fn set_reminder(ctx: &ReducerContext, message_id: u32, at: Timestamp) -> Result<(), String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    if ctx.db.message().id().find(message_id).is_none() {
+        return Err("Message not found".to_string());
+    }
+
+    insert_reminder(ctx, creds.user_id, message_id, at)
+}
+
+#[reducer]
+pub fn cancel_reminder(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    match ctx.db.reminder().scheduled_id().find(id) {
+        Some(reminder) if reminder.user_id == creds.user_id => {
+            ctx.db.reminder().scheduled_id().delete(id);
+            Ok(())
+        }
+        _ => Err("Reminder not found".to_string()),
+    }
+}
+
+#[reducer]
+pub fn fire_reminder(ctx: &ReducerContext, reminder: Reminder) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer is called only by scheduler".to_string());
+    }
+
+    ctx.db.notification().insert(Notification {
+        id: 0,
+        user_id: reminder.user_id,
+        message_id: reminder.message_id,
+        created_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+/// Fire notification again later
+#[reducer]
+pub fn snooze_notification(ctx: &ReducerContext, id: u64, until: Timestamp) -> Result<(), String> {
+    let notification = get_own_notification(ctx, id)?;
+
+    ctx.db.notification().id().delete(id);
+    insert_reminder(ctx, notification.user_id, notification.message_id, until)
+}
+
+#[reducer]
+pub fn dismiss_notification(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    get_own_notification(ctx, id)?;
+    ctx.db.notification().id().delete(id);
+
+    Ok(())
+}
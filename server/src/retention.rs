@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use spacetimedb::*;
+
+use crate::{get_admin, get_config, message, purge_message, set_config};
+
+/// Max messages removed by one run
+const BATCH_SIZE: usize = 500;
+/// Interval of regular retention runs
+const RUN_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// Delay of next run when batch is full
+const CONTINUE_DELAY: Duration = Duration::from_secs(1);
+const DAY_MICROS: i64 = 24 * 60 * 60 * 1_000_000;
+/// Longest retention, about 100 years
+const MAX_DAYS: u32 = 36_500;
+
+#[table(name=retention_job, scheduled(apply_retention))]
+pub struct RetentionJob {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+#[table(name=archived_message)]
+// Expired message kept for compliance, attachment data is removed
+pub struct ArchivedMessage {
+    #[primary_key]
+    id: u32,
+    sender: u32,
+    reply: Option<u32>,
+    sent: Timestamp,
+    edited: Option<Timestamp>,
+    text: String,
+    file_name: Option<String>,
+    archived_at: Timestamp,
+}
+
+#[table(name=retention_run, public)]
+// Audit trail of retention runs, has no message content
+pub struct RetentionRun {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    at: Timestamp,
+    // Messages were archived, not only removed
+    archived: bool,
+    messages: u32,
+    files: u32,
+    // Messages sent before it were expired
+    cutoff: Timestamp,
+}
+
+/// Set server-wide retention, `None` keeps messages forever
+#[reducer]
+pub fn set_retention(ctx: &ReducerContext, days: Option<u32>, archive: bool) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+
+    if days == Some(0) {
+        return Err("Retention must be at least one day".to_string());
+    }
+    if days.is_some_and(|days| days > MAX_DAYS) {
+        return Err(format!("Retention must be at most {} days", MAX_DAYS));
+    }
+
+    let mut config = get_config(ctx);
+    config.retention_days = days;
+    config.archive_expired = archive;
+    set_config(ctx, config);
+
+    // Restart schedule
+    let jobs = ctx.db.retention_job().iter().map(|job| job.scheduled_id).collect::<Vec<_>>();
+    for id in jobs {
+        ctx.db.retention_job().scheduled_id().delete(id);
+    }
+    if days.is_some() {
+        ctx.db.retention_job().insert(RetentionJob { scheduled_id: 0, scheduled_at: ctx.timestamp.into() });
+        ctx.db.retention_job().insert(RetentionJob { scheduled_id: 0, scheduled_at: RUN_INTERVAL.into() });
+    }
+
+    log::info!("User {} set retention to {:?} days, archive: {}", creds.user_id, days, archive);
+    Ok(())
+}
+
+#[reducer]
+pub fn apply_retention(ctx: &ReducerContext, _job: RetentionJob) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer is called only by scheduler".to_string());
+    }
+
+    let config = get_config(ctx);
+    let Some(days) = config.retention_days else {
+        return Ok(());
+    };
+
+    // Out of range cutoff keeps every message
+    let cutoff = (days as i64).checked_mul(DAY_MICROS)
+        .and_then(|age| ctx.timestamp.to_micros_since_unix_epoch().checked_sub(age))
+        .map(Timestamp::from_micros_since_unix_epoch);
+    let Some(cutoff) = cutoff else {
+        return Ok(());
+    };
+
+    let expired = ctx.db.message().iter()
+        .filter(|m| m.sent < cutoff)
+        .take(BATCH_SIZE)
+        .collect::<Vec<_>>();
+
+    if expired.is_empty() {
+        return Ok(());
+    }
+
+    let count = expired.len();
+    let mut files = 0;
+    for message in expired {
+        if message.file.is_some() {
+            files += 1;
+        }
+
+        if config.archive_expired {
+            ctx.db.archived_message().insert(ArchivedMessage {
+                id: message.id,
+                sender: message.sender,
+                reply: message.reply,
+                sent: message.sent,
+                edited: message.edited,
+                text: message.text.clone(),
+                file_name: message.file.as_ref().map(|f| f.name.clone()),
+                archived_at: ctx.timestamp,
+            });
+        }
+
+        purge_message(ctx, &message);
+    }
+
+    ctx.db.retention_run().insert(RetentionRun {
+        id: 0,
+        at: ctx.timestamp,
+        archived: config.archive_expired,
+        messages: count as u32,
+        files,
+        cutoff,
+    });
+    log::info!(
+        "Retention {} {} messages and {} files older than {} days",
+        if config.archive_expired { "archived" } else { "removed" },
+        count,
+        files,
+        days,
+    );
+
+    // More expired messages are left, continue shortly
+    if count == BATCH_SIZE {
+        ctx.db.retention_job().insert(RetentionJob {
+            scheduled_id: 0,
+            scheduled_at: (ctx.timestamp + TimeDuration::from(CONTINUE_DELAY)).into(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use spacetimedb::*;
+
+use crate::{bot, credentials, get_creds, post_message, take_upload, temp_file};
+
+/// Max pending scheduled messages of user
+const MAX_SCHEDULED: usize = 50;
+
+#[table(name=scheduled_message, public, scheduled(post_scheduled_message))]
+// Message posted later, visible only to its sender
+pub struct ScheduledMessage {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    #[index(btree)]
+    sender: u32,
+    text: String,
+    reply: Option<u32>,
+    // Uploaded temp file
+    file: Option<u32>,
+}
+
+#[client_visibility_filter]
+const SCHEDULED_MESSAGE_FILTER: Filter = Filter::Sql(
+    "SELECT m.* FROM scheduled_message m JOIN session s ON m.sender = s.user_id WHERE s.identity = :sender"
+);
+
+fn get_own(ctx: &ReducerContext, id: u64) -> Result<ScheduledMessage, String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+
+    match ctx.db.scheduled_message().scheduled_id().find(id) {
+        Some(message) if message.sender == creds.user_id => Ok(message),
+        _ => Err("Scheduled message not found".to_string()),
+    }
+}
+
+fn check_time(ctx: &ReducerContext, at: Timestamp) -> Result<(), String> {
+    if at <= ctx.timestamp {
+        return Err("Scheduled time is in the past".to_string());
+    }
+
+    Ok(())
+}
+
+/// Remove scheduled message with its upload
+fn cancel(ctx: &ReducerContext, message: ScheduledMessage) {
+    if let Some(file) = message.file {
+        ctx.db.temp_file().id().delete(file);
+    }
+    ctx.db.scheduled_message().scheduled_id().delete(message.scheduled_id);
+}
+
+/// Cancel all scheduled messages of deleted user
+pub fn remove_user(ctx: &ReducerContext, user_id: u32) {
+    let messages = ctx.db.scheduled_message().sender().filter(&user_id).collect::<Vec<_>>();
+    for message in messages {
+        cancel(ctx, message);
+    }
+}
+
+#[reducer]
+pub fn schedule_message(ctx: &ReducerContext, text: String, reply: Option<u32>, at: Timestamp) -> Result<(), String> {
+    let text = text.trim().to_string();
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+    check_time(ctx, at)?;
+
+    if ctx.db.scheduled_message().sender().filter(&creds.user_id).count() >= MAX_SCHEDULED {
+        return Err(format!("At most {} messages can be scheduled", MAX_SCHEDULED));
+    }
+
+    let file = take_upload(ctx)?.map(|temp| temp.id);
+    if text.is_empty() && file.is_none() {
+        return Err("Empty message".to_string());
+    }
+
+    ctx.db.scheduled_message().insert(ScheduledMessage {
+        scheduled_id: 0,
+        scheduled_at: at.into(),
+        sender: creds.user_id,
+        text,
+        reply,
+        file,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn edit_scheduled_message(ctx: &ReducerContext, id: u64, text: String, at: Timestamp) -> Result<(), String> {
+    let mut message = get_own(ctx, id)?;
+    check_time(ctx, at)?;
+
+    let text = text.trim().to_string();
+    if text.is_empty() && message.file.is_none() {
+        return Err("Empty message".to_string());
+    }
+
+    message.text = text;
+    message.scheduled_at = at.into();
+    ctx.db.scheduled_message().scheduled_id().update(message);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn cancel_scheduled_message(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    let message = get_own(ctx, id)?;
+    cancel(ctx, message);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn post_scheduled_message(ctx: &ReducerContext, message: ScheduledMessage) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer is called only by scheduler".to_string());
+    }
+
+    // Sender account is deleted
+    if ctx.db.credentials().user_id().find(message.sender).is_none() {
+        cancel(ctx, message);
+        return Ok(());
+    }
+
+    // Failed message is dropped, scheduled row is removed anyway
+    let id = message.scheduled_id;
+    let upload = message.file.and_then(|id| ctx.db.temp_file().id().find(id));
+    if let Err(e) = post_message(ctx, message.sender, message.text, message.reply, upload) {
+        log::warn!("Scheduled message {} is not posted: {}", id, e);
+        if let Some(file) = message.file {
+            ctx.db.temp_file().id().delete(file);
+        }
+    }
+
+    Ok(())
+}
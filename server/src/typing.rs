@@ -0,0 +1,72 @@
+use spacetimedb::*;
+
+use crate::{bot, get_creds, rate_limit};
+
+/// Typing state lifetime, client repeats `set_typing` while user types
+const TYPING_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+#[table(name=typing, public)]
+// User typing in chat, there is only one conversation
+pub struct Typing {
+    #[primary_key]
+    user_id: u32,
+    expires: Timestamp,
+}
+
+#[table(name=typing_expiry, scheduled(expire_typing))]
+// Scheduled removal of typing state
+pub struct TypingExpiry {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    user_id: u32,
+}
+
+/// Remove typing state of user, e.g. after message is sent
+pub fn clear(ctx: &ReducerContext, user_id: u32) {
+    ctx.db.typing().user_id().delete(user_id);
+}
+
+#[reducer]
+pub fn set_typing(ctx: &ReducerContext) -> Result<(), String> {
+    let Some(creds) = get_creds(ctx) else {
+        return Err("You are not logged in".to_string());
+    };
+    bot::check_write(ctx, creds.user_id)?;
+    rate_limit::check(ctx, "set_typing", Some(creds.user_id))?;
+
+    let expires = ctx.timestamp + TimeDuration::from(TYPING_TIMEOUT);
+    let typing = Typing { user_id: creds.user_id, expires };
+    if ctx.db.typing().user_id().find(creds.user_id).is_some() {
+        ctx.db.typing().user_id().update(typing);
+    } else {
+        ctx.db.typing().insert(typing);
+    }
+
+    ctx.db.typing_expiry().insert(TypingExpiry {
+        scheduled_id: 0,
+        scheduled_at: expires.into(),
+        user_id: creds.user_id,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn expire_typing(ctx: &ReducerContext, expiry: TypingExpiry) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer is called only by scheduler".to_string());
+    }
+
+    // State could be renewed after this expiry was scheduled
+    let Some(typing) = ctx.db.typing().user_id().find(expiry.user_id) else {
+        return Ok(());
+    };
+
+    if typing.expires <= ctx.timestamp {
+        clear(ctx, expiry.user_id);
+    }
+
+    Ok(())
+}
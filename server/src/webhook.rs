@@ -0,0 +1,77 @@
+use spacetimedb::*;
+
+use crate::{bot, get_admin, user};
+
+const MIN_TOKEN_LENGTH: usize = 32;
+
+#[table(name=webhook, public)]
+// Incoming webhook, gateway connected as its bot posts with it
+pub struct Webhook {
+    #[primary_key]
+    #[auto_inc]
+    id: u32,
+    // Secret part of hook url
+    #[unique]
+    token: String,
+    #[index(btree)]
+    bot_id: u32,
+    name: String,
+    created_at: Timestamp,
+}
+
+// Tokens are visible only to bot of webhook
+#[client_visibility_filter]
+const WEBHOOK_FILTER: Filter = Filter::Sql(
+    "SELECT w.* FROM webhook w JOIN session s ON w.bot_id = s.user_id WHERE s.identity = :sender"
+);
+
+/// Set webhook token generated by admin client
+#[reducer]
+pub fn create_webhook(ctx: &ReducerContext, bot_id: u32, name: String, token: String) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+
+    if !ctx.db.user().id().find(bot_id).is_some_and(|u| u.is_bot && !u.deleted) {
+        return Err("Bot not found".to_string());
+    }
+    bot::check_write(ctx, bot_id).map_err(|_| "Bot token has no permission to post messages".to_string())?;
+    if token.len() < MIN_TOKEN_LENGTH {
+        return Err(format!("Token must be at least {} characters long", MIN_TOKEN_LENGTH));
+    }
+
+    let name = name.trim().to_string();
+    ctx.db
+        .webhook()
+        .try_insert(Webhook { id: 0, token, bot_id, name, created_at: ctx.timestamp })
+        .map_err(|_| "Webhook with this token already exists".to_string())?;
+
+    log::info!("User {} created webhook for bot {}", creds.user_id, bot_id);
+    Ok(())
+}
+
+/// Revoke single webhook
+#[reducer]
+pub fn delete_webhook(ctx: &ReducerContext, id: u32) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+
+    let Some(webhook) = ctx.db.webhook().id().find(id) else {
+        return Err("Webhook not found".to_string());
+    };
+    ctx.db.webhook().id().delete(id);
+
+    log::info!("User {} removed webhook {} of bot {}", creds.user_id, id, webhook.bot_id);
+    Ok(())
+}
+
+/// Remove all webhooks posting as bot
+#[reducer]
+pub fn delete_webhooks(ctx: &ReducerContext, bot_id: u32) -> Result<(), String> {
+    let creds = get_admin(ctx)?;
+
+    let count = ctx.db.webhook().bot_id().delete(&bot_id);
+    if count == 0 {
+        return Err("Bot has no webhooks".to_string());
+    }
+
+    log::info!("User {} removed {} webhooks of bot {}", creds.user_id, count, bot_id);
+    Ok(())
+}
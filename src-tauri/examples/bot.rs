@@ -0,0 +1,54 @@
+//! Headless bot posting one message:
+//! `CHAT_BOT_TOKEN=<token> cargo run --example bot -- [address] <text>`
+use std::sync::mpsc;
+
+use chat_lib::bindings::*;
+use spacetimedb_sdk::{DbContext, Status};
+
+const DB_NAME: &str = "chat";
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let text = args.pop().expect("Message text is required");
+    let addr = args.pop().unwrap_or("localhost".to_string());
+    let token = std::env::var("CHAT_BOT_TOKEN").expect("CHAT_BOT_TOKEN is not set");
+
+    let (sender, receiver) = mpsc::channel::<Result<(), String>>();
+
+    let connection = DbConnection::builder()
+        .with_module_name(DB_NAME)
+        .with_uri(format!("http://{}:3000", addr))
+        .build()
+        .expect("Connection error");
+
+    let done = sender.clone();
+    connection.reducers.on_bot_login(move |ctx, _token| match &ctx.event.status {
+        Status::Committed => {
+            ctx.reducers.send_message(text.clone(), None).expect("Spacetime error");
+        }
+        Status::Failed(err) => done.send(Err(err.to_string())).unwrap(),
+        _ => done.send(Err("Out of energy".to_string())).unwrap(),
+    });
+
+    connection.reducers.on_send_message(move |ctx, _text, _reply| match &ctx.event.status {
+        Status::Committed => sender.send(Ok(())).unwrap(),
+        Status::Failed(err) => sender.send(Err(err.to_string())).unwrap(),
+        _ => sender.send(Err("Out of energy".to_string())).unwrap(),
+    });
+
+    connection.run_threaded();
+
+    connection
+        .reducers
+        .begin_auth("bot_login".to_string(), String::new(), token.clone())
+        .expect("Spacetime error");
+    connection.reducers.bot_login(token).expect("Spacetime error");
+
+    let result = receiver.recv().expect("Connection closed");
+    connection.disconnect().ok();
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
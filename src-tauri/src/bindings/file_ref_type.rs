@@ -4,12 +4,16 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::image_info_type::ImageInfo;
+
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub struct FileRef {
     pub id: u32,
     pub name: String,
     pub size: u64,
+    pub mime: String,
+    pub image: Option<ImageInfo>,
 }
 
 impl __sdk::InModule for FileRef {
@@ -3,6 +3,7 @@
 
 #![allow(unused, clippy::all)]
 use super::file_ref_type::FileRef;
+use super::mention_span_type::MentionSpan;
 use super::message_type::Message;
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
@@ -5,6 +5,7 @@
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 use super::file_ref_type::FileRef;
+use super::mention_span_type::MentionSpan;
 
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
@@ -16,6 +17,8 @@ pub struct Message {
     pub sent: __sdk::Timestamp,
     pub text: String,
     pub file: Option<FileRef>,
+    pub mentions: Vec<u32>,
+    pub mention_spans: Vec<MentionSpan>,
 }
 
 impl __sdk::InModule for Message {
@@ -4,52 +4,263 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod ack_command_event_reducer;
+pub mod apply_retention_reducer;
+pub mod archived_message_table;
+pub mod archived_message_type;
+pub mod auth_attempt_table;
+pub mod auth_attempt_type;
+pub mod avatar_table;
+pub mod avatar_type;
+pub mod begin_auth_reducer;
+pub mod bot_login_reducer;
+pub mod bot_scope_type;
+pub mod bot_token_table;
+pub mod bot_token_type;
+pub mod cancel_reminder_reducer;
+pub mod cancel_scheduled_message_reducer;
+pub mod cancel_stream_reducer;
+pub mod change_password_reducer;
 pub mod client_connected_reducer;
 pub mod client_disconnected_reducer;
+pub mod command_event_expiry_table;
+pub mod command_event_expiry_type;
+pub mod command_event_table;
+pub mod command_event_type;
+pub mod config_table;
+pub mod config_type;
+pub mod create_bot_reducer;
+pub mod create_webhook_reducer;
 pub mod credentials_table;
+pub mod delete_account_reducer;
+pub mod delete_webhook_reducer;
+pub mod delete_webhooks_reducer;
+pub mod dismiss_notification_reducer;
 pub mod edit_message_reducer;
+pub mod edit_scheduled_message_reducer;
+pub mod expire_command_event_reducer;
+pub mod expire_typing_reducer;
 pub mod file_ref_type;
 pub mod file_request_type;
 pub mod file_table;
 pub mod file_type;
+pub mod fire_reminder_reducer;
+pub mod image_info_type;
+pub mod import_messages_reducer;
+pub mod import_ref_table;
+pub mod import_ref_type;
+pub mod imported_file_type;
+pub mod imported_message_type;
+pub mod invoke_command_reducer;
+pub mod issue_bot_token_reducer;
+pub mod issue_reset_code_reducer;
+pub mod kick_table;
+pub mod kick_type;
+pub mod kick_user_reducer;
 pub mod login_reducer;
 pub mod logout_reducer;
+pub mod mark_read_reducer;
+pub mod mention_kind_type;
+pub mod mention_span_type;
 pub mod message_table;
 pub mod message_type;
+pub mod notification_table;
+pub mod notification_type;
+pub mod password_reset_table;
+pub mod password_reset_type;
+pub mod pin_message_reducer;
+pub mod pin_table;
+pub mod pin_type;
+pub mod post_scheduled_message_reducer;
+pub mod presence_type;
+pub mod profile_type;
+pub mod rate_bucket_table;
+pub mod rate_bucket_type;
+pub mod rate_limit_table;
+pub mod rate_limit_type;
+pub mod read_state_table;
+pub mod read_state_type;
+pub mod register_command_reducer;
+pub mod reminder_table;
+pub mod reminder_type;
 pub mod remove_message_reducer;
 pub mod request_stream_reducer;
 pub mod request_table;
+pub mod reset_password_reducer;
+pub mod retention_job_table;
+pub mod retention_job_type;
+pub mod retention_run_table;
+pub mod retention_run_type;
+pub mod revoke_bot_token_reducer;
 pub mod room_table;
+pub mod schedule_message_reducer;
+pub mod scheduled_message_table;
+pub mod scheduled_message_type;
 pub mod send_message_reducer;
 pub mod send_packet_reducer;
 pub mod send_voice_packet_reducer;
+pub mod session_table;
+pub mod session_type;
 pub mod set_avatar_reducer;
+pub mod set_presence_reducer;
+pub mod set_purge_deleted_messages_reducer;
+pub mod set_rate_limit_reducer;
+pub mod set_read_receipts_reducer;
+pub mod set_reminder_reducer;
+pub mod set_retention_reducer;
+pub mod set_topic_reducer;
+pub mod set_typing_reducer;
 pub mod signup_reducer;
+pub mod slash_command_table;
+pub mod slash_command_type;
+pub mod snooze_notification_reducer;
 pub mod temp_file_table;
 pub mod temp_file_type;
+pub mod thumbnail_table;
+pub mod thumbnail_type;
+pub mod typing_expiry_table;
+pub mod typing_expiry_type;
+pub mod typing_table;
+pub mod typing_type;
+pub mod unpin_message_reducer;
+pub mod unregister_command_reducer;
+pub mod update_profile_reducer;
 pub mod user_credentials_type;
+pub mod user_status_type;
 pub mod user_table;
 pub mod user_type;
 pub mod voice_packet_table;
 pub mod voice_packet_type;
 pub mod voice_room_type;
+pub mod webhook_table;
+pub mod webhook_type;
 
+pub use ack_command_event_reducer::{
+    ack_command_event, set_flags_for_ack_command_event, AckCommandEventCallbackId,
+};
+pub use apply_retention_reducer::{
+    apply_retention, set_flags_for_apply_retention, ApplyRetentionCallbackId,
+};
+pub use archived_message_table::*;
+pub use archived_message_type::ArchivedMessage;
+pub use auth_attempt_table::*;
+pub use auth_attempt_type::AuthAttempt;
+pub use avatar_table::*;
+pub use avatar_type::Avatar;
+pub use begin_auth_reducer::{begin_auth, set_flags_for_begin_auth, BeginAuthCallbackId};
+pub use bot_login_reducer::{bot_login, set_flags_for_bot_login, BotLoginCallbackId};
+pub use bot_scope_type::BotScope;
+pub use bot_token_table::*;
+pub use bot_token_type::BotToken;
+pub use cancel_reminder_reducer::{
+    cancel_reminder, set_flags_for_cancel_reminder, CancelReminderCallbackId,
+};
+pub use cancel_scheduled_message_reducer::{
+    cancel_scheduled_message, set_flags_for_cancel_scheduled_message,
+    CancelScheduledMessageCallbackId,
+};
+pub use cancel_stream_reducer::{
+    cancel_stream, set_flags_for_cancel_stream, CancelStreamCallbackId,
+};
+pub use change_password_reducer::{
+    change_password, set_flags_for_change_password, ChangePasswordCallbackId,
+};
 pub use client_connected_reducer::{
     client_connected, set_flags_for_client_connected, ClientConnectedCallbackId,
 };
 pub use client_disconnected_reducer::{
     client_disconnected, set_flags_for_client_disconnected, ClientDisconnectedCallbackId,
 };
+pub use command_event_expiry_table::*;
+pub use command_event_expiry_type::CommandEventExpiry;
+pub use command_event_table::*;
+pub use command_event_type::CommandEvent;
+pub use config_table::*;
+pub use config_type::Config;
+pub use create_bot_reducer::{create_bot, set_flags_for_create_bot, CreateBotCallbackId};
+pub use create_webhook_reducer::{
+    create_webhook, set_flags_for_create_webhook, CreateWebhookCallbackId,
+};
 pub use credentials_table::*;
+pub use delete_account_reducer::{
+    delete_account, set_flags_for_delete_account, DeleteAccountCallbackId,
+};
+pub use delete_webhook_reducer::{
+    delete_webhook, set_flags_for_delete_webhook, DeleteWebhookCallbackId,
+};
+pub use delete_webhooks_reducer::{
+    delete_webhooks, set_flags_for_delete_webhooks, DeleteWebhooksCallbackId,
+};
+pub use dismiss_notification_reducer::{
+    dismiss_notification, set_flags_for_dismiss_notification, DismissNotificationCallbackId,
+};
 pub use edit_message_reducer::{edit_message, set_flags_for_edit_message, EditMessageCallbackId};
+pub use edit_scheduled_message_reducer::{
+    edit_scheduled_message, set_flags_for_edit_scheduled_message, EditScheduledMessageCallbackId,
+};
+pub use expire_command_event_reducer::{
+    expire_command_event, set_flags_for_expire_command_event, ExpireCommandEventCallbackId,
+};
+pub use expire_typing_reducer::{
+    expire_typing, set_flags_for_expire_typing, ExpireTypingCallbackId,
+};
 pub use file_ref_type::FileRef;
 pub use file_request_type::FileRequest;
 pub use file_table::*;
 pub use file_type::File;
+pub use fire_reminder_reducer::{
+    fire_reminder, set_flags_for_fire_reminder, FireReminderCallbackId,
+};
+pub use image_info_type::ImageInfo;
+pub use import_messages_reducer::{
+    import_messages, set_flags_for_import_messages, ImportMessagesCallbackId,
+};
+pub use import_ref_table::*;
+pub use import_ref_type::ImportRef;
+pub use imported_file_type::ImportedFile;
+pub use imported_message_type::ImportedMessage;
+pub use invoke_command_reducer::{
+    invoke_command, set_flags_for_invoke_command, InvokeCommandCallbackId,
+};
+pub use issue_bot_token_reducer::{
+    issue_bot_token, set_flags_for_issue_bot_token, IssueBotTokenCallbackId,
+};
+pub use issue_reset_code_reducer::{
+    issue_reset_code, set_flags_for_issue_reset_code, IssueResetCodeCallbackId,
+};
+pub use kick_table::*;
+pub use kick_type::Kick;
+pub use kick_user_reducer::{kick_user, set_flags_for_kick_user, KickUserCallbackId};
 pub use login_reducer::{login, set_flags_for_login, LoginCallbackId};
 pub use logout_reducer::{logout, set_flags_for_logout, LogoutCallbackId};
+pub use mark_read_reducer::{mark_read, set_flags_for_mark_read, MarkReadCallbackId};
+pub use mention_kind_type::MentionKind;
+pub use mention_span_type::MentionSpan;
 pub use message_table::*;
 pub use message_type::Message;
+pub use notification_table::*;
+pub use notification_type::Notification;
+pub use password_reset_table::*;
+pub use password_reset_type::PasswordReset;
+pub use pin_message_reducer::{pin_message, set_flags_for_pin_message, PinMessageCallbackId};
+pub use pin_table::*;
+pub use pin_type::Pin;
+pub use post_scheduled_message_reducer::{
+    post_scheduled_message, set_flags_for_post_scheduled_message, PostScheduledMessageCallbackId,
+};
+pub use presence_type::Presence;
+pub use profile_type::Profile;
+pub use rate_bucket_table::*;
+pub use rate_bucket_type::RateBucket;
+pub use rate_limit_table::*;
+pub use rate_limit_type::RateLimit;
+pub use read_state_table::*;
+pub use read_state_type::ReadState;
+pub use register_command_reducer::{
+    register_command, set_flags_for_register_command, RegisterCommandCallbackId,
+};
+pub use reminder_table::*;
+pub use reminder_type::Reminder;
 pub use remove_message_reducer::{
     remove_message, set_flags_for_remove_message, RemoveMessageCallbackId,
 };
@@ -57,22 +268,79 @@ pub use request_stream_reducer::{
     request_stream, set_flags_for_request_stream, RequestStreamCallbackId,
 };
 pub use request_table::*;
+pub use reset_password_reducer::{
+    reset_password, set_flags_for_reset_password, ResetPasswordCallbackId,
+};
+pub use retention_job_table::*;
+pub use retention_job_type::RetentionJob;
+pub use retention_run_table::*;
+pub use retention_run_type::RetentionRun;
+pub use revoke_bot_token_reducer::{
+    revoke_bot_token, set_flags_for_revoke_bot_token, RevokeBotTokenCallbackId,
+};
 pub use room_table::*;
+pub use schedule_message_reducer::{
+    schedule_message, set_flags_for_schedule_message, ScheduleMessageCallbackId,
+};
+pub use scheduled_message_table::*;
+pub use scheduled_message_type::ScheduledMessage;
 pub use send_message_reducer::{send_message, set_flags_for_send_message, SendMessageCallbackId};
 pub use send_packet_reducer::{send_packet, set_flags_for_send_packet, SendPacketCallbackId};
 pub use send_voice_packet_reducer::{
     send_voice_packet, set_flags_for_send_voice_packet, SendVoicePacketCallbackId,
 };
+pub use session_table::*;
+pub use session_type::Session;
 pub use set_avatar_reducer::{set_avatar, set_flags_for_set_avatar, SetAvatarCallbackId};
+pub use set_presence_reducer::{set_flags_for_set_presence, set_presence, SetPresenceCallbackId};
+pub use set_purge_deleted_messages_reducer::{
+    set_flags_for_set_purge_deleted_messages, set_purge_deleted_messages,
+    SetPurgeDeletedMessagesCallbackId,
+};
+pub use set_rate_limit_reducer::{
+    set_flags_for_set_rate_limit, set_rate_limit, SetRateLimitCallbackId,
+};
+pub use set_read_receipts_reducer::{
+    set_flags_for_set_read_receipts, set_read_receipts, SetReadReceiptsCallbackId,
+};
+pub use set_reminder_reducer::{set_flags_for_set_reminder, set_reminder, SetReminderCallbackId};
+pub use set_retention_reducer::{
+    set_flags_for_set_retention, set_retention, SetRetentionCallbackId,
+};
+pub use set_topic_reducer::{set_flags_for_set_topic, set_topic, SetTopicCallbackId};
+pub use set_typing_reducer::{set_flags_for_set_typing, set_typing, SetTypingCallbackId};
 pub use signup_reducer::{set_flags_for_signup, signup, SignupCallbackId};
+pub use slash_command_table::*;
+pub use slash_command_type::SlashCommand;
+pub use snooze_notification_reducer::{
+    set_flags_for_snooze_notification, snooze_notification, SnoozeNotificationCallbackId,
+};
 pub use temp_file_table::*;
 pub use temp_file_type::TempFile;
+pub use thumbnail_table::*;
+pub use thumbnail_type::Thumbnail;
+pub use typing_expiry_table::*;
+pub use typing_expiry_type::TypingExpiry;
+pub use typing_table::*;
+pub use typing_type::Typing;
+pub use unpin_message_reducer::{
+    set_flags_for_unpin_message, unpin_message, UnpinMessageCallbackId,
+};
+pub use unregister_command_reducer::{
+    set_flags_for_unregister_command, unregister_command, UnregisterCommandCallbackId,
+};
+pub use update_profile_reducer::{
+    set_flags_for_update_profile, update_profile, UpdateProfileCallbackId,
+};
 pub use user_credentials_type::UserCredentials;
+pub use user_status_type::UserStatus;
 pub use user_table::*;
 pub use user_type::User;
 pub use voice_packet_table::*;
 pub use voice_packet_type::VoicePacket;
 pub use voice_room_type::VoiceRoom;
+pub use webhook_table::*;
+pub use webhook_type::Webhook;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -82,18 +350,188 @@ pub use voice_room_type::VoiceRoom;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
+    AckCommandEvent {
+        id: u64,
+    },
+    ApplyRetention {
+        _job: RetentionJob,
+    },
+    BeginAuth {
+        reducer: String,
+        name: String,
+        secret: String,
+    },
+    BotLogin {
+        token: String,
+    },
+    CancelReminder {
+        id: u64,
+    },
+    CancelScheduledMessage {
+        id: u64,
+    },
+    CancelStream,
+    ChangePassword {
+        old_password: String,
+        new_password: String,
+    },
     ClientConnected,
     ClientDisconnected,
-    EditMessage { id: u32, text: String },
-    Login { name: String, password: String },
+    CreateBot {
+        name: String,
+        display_name: String,
+    },
+    CreateWebhook {
+        bot_id: u32,
+        name: String,
+        token: String,
+    },
+    DeleteAccount {
+        password: String,
+    },
+    DeleteWebhook {
+        id: u32,
+    },
+    DeleteWebhooks {
+        bot_id: u32,
+    },
+    DismissNotification {
+        id: u64,
+    },
+    EditMessage {
+        id: u32,
+        text: String,
+    },
+    EditScheduledMessage {
+        id: u64,
+        text: String,
+        at: __sdk::Timestamp,
+    },
+    ExpireCommandEvent {
+        expiry: CommandEventExpiry,
+    },
+    ExpireTyping {
+        expiry: TypingExpiry,
+    },
+    FireReminder {
+        reminder: Reminder,
+    },
+    ImportMessages {
+        source: String,
+        messages: Vec<ImportedMessage>,
+    },
+    InvokeCommand {
+        name: String,
+        args: String,
+        reply: Option<u32>,
+    },
+    IssueBotToken {
+        bot_id: u32,
+        token: String,
+        scopes: Vec<BotScope>,
+    },
+    IssueResetCode {
+        user_id: u32,
+        code: String,
+    },
+    KickUser {
+        user_id: u32,
+    },
+    Login {
+        name: String,
+        password: String,
+    },
     Logout,
-    RemoveMessage { id: u32 },
-    RequestStream { name: String, size: u64 },
-    SendMessage { text: String, reply: Option<u32> },
-    SendPacket { pocket: Vec<u8> },
-    SendVoicePacket { data: Vec<f32> },
-    SetAvatar { data: Vec<u8> },
-    Signup { name: String, password: String },
+    MarkRead {
+        message_id: u32,
+    },
+    PinMessage {
+        message_id: u32,
+    },
+    PostScheduledMessage {
+        message: ScheduledMessage,
+    },
+    RegisterCommand {
+        name: String,
+        hint: String,
+        description: String,
+    },
+    RemoveMessage {
+        id: u32,
+    },
+    RequestStream {
+        name: String,
+        size: u64,
+    },
+    ResetPassword {
+        name: String,
+        code: String,
+        password: String,
+    },
+    RevokeBotToken {
+        bot_id: u32,
+    },
+    ScheduleMessage {
+        text: String,
+        reply: Option<u32>,
+        at: __sdk::Timestamp,
+    },
+    SendMessage {
+        text: String,
+        reply: Option<u32>,
+    },
+    SendPacket {
+        pocket: Vec<u8>,
+    },
+    SendVoicePacket {
+        data: Vec<f32>,
+    },
+    SetAvatar {
+        data: Vec<u8>,
+    },
+    SetPresence {
+        presence: Presence,
+    },
+    SetPurgeDeletedMessages {
+        value: bool,
+    },
+    SetRateLimit {
+        reducer: String,
+        capacity: u32,
+        refill_ms: u64,
+    },
+    SetReadReceipts {
+        enabled: bool,
+    },
+    SetReminder {
+        message_id: u32,
+        at: __sdk::Timestamp,
+    },
+    SetRetention {
+        days: Option<u32>,
+        archive: bool,
+    },
+    SetTopic {
+        topic: String,
+    },
+    SetTyping,
+    Signup {
+        name: String,
+        password: String,
+    },
+    SnoozeNotification {
+        id: u64,
+        until: __sdk::Timestamp,
+    },
+    UnpinMessage {
+        message_id: u32,
+    },
+    UnregisterCommand {
+        name: String,
+    },
+    UpdateProfile {
+        profile: Profile,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -103,18 +541,60 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::AckCommandEvent { .. } => "ack_command_event",
+            Reducer::ApplyRetention { .. } => "apply_retention",
+            Reducer::BeginAuth { .. } => "begin_auth",
+            Reducer::BotLogin { .. } => "bot_login",
+            Reducer::CancelReminder { .. } => "cancel_reminder",
+            Reducer::CancelScheduledMessage { .. } => "cancel_scheduled_message",
+            Reducer::CancelStream => "cancel_stream",
+            Reducer::ChangePassword { .. } => "change_password",
             Reducer::ClientConnected => "client_connected",
             Reducer::ClientDisconnected => "client_disconnected",
+            Reducer::CreateBot { .. } => "create_bot",
+            Reducer::CreateWebhook { .. } => "create_webhook",
+            Reducer::DeleteAccount { .. } => "delete_account",
+            Reducer::DeleteWebhook { .. } => "delete_webhook",
+            Reducer::DeleteWebhooks { .. } => "delete_webhooks",
+            Reducer::DismissNotification { .. } => "dismiss_notification",
             Reducer::EditMessage { .. } => "edit_message",
+            Reducer::EditScheduledMessage { .. } => "edit_scheduled_message",
+            Reducer::ExpireCommandEvent { .. } => "expire_command_event",
+            Reducer::ExpireTyping { .. } => "expire_typing",
+            Reducer::FireReminder { .. } => "fire_reminder",
+            Reducer::ImportMessages { .. } => "import_messages",
+            Reducer::InvokeCommand { .. } => "invoke_command",
+            Reducer::IssueBotToken { .. } => "issue_bot_token",
+            Reducer::IssueResetCode { .. } => "issue_reset_code",
+            Reducer::KickUser { .. } => "kick_user",
             Reducer::Login { .. } => "login",
             Reducer::Logout => "logout",
+            Reducer::MarkRead { .. } => "mark_read",
+            Reducer::PinMessage { .. } => "pin_message",
+            Reducer::PostScheduledMessage { .. } => "post_scheduled_message",
+            Reducer::RegisterCommand { .. } => "register_command",
             Reducer::RemoveMessage { .. } => "remove_message",
             Reducer::RequestStream { .. } => "request_stream",
+            Reducer::ResetPassword { .. } => "reset_password",
+            Reducer::RevokeBotToken { .. } => "revoke_bot_token",
+            Reducer::ScheduleMessage { .. } => "schedule_message",
             Reducer::SendMessage { .. } => "send_message",
             Reducer::SendPacket { .. } => "send_packet",
             Reducer::SendVoicePacket { .. } => "send_voice_packet",
             Reducer::SetAvatar { .. } => "set_avatar",
+            Reducer::SetPresence { .. } => "set_presence",
+            Reducer::SetPurgeDeletedMessages { .. } => "set_purge_deleted_messages",
+            Reducer::SetRateLimit { .. } => "set_rate_limit",
+            Reducer::SetReadReceipts { .. } => "set_read_receipts",
+            Reducer::SetReminder { .. } => "set_reminder",
+            Reducer::SetRetention { .. } => "set_retention",
+            Reducer::SetTopic { .. } => "set_topic",
+            Reducer::SetTyping => "set_typing",
             Reducer::Signup { .. } => "signup",
+            Reducer::SnoozeNotification { .. } => "snooze_notification",
+            Reducer::UnpinMessage { .. } => "unpin_message",
+            Reducer::UnregisterCommand { .. } => "unregister_command",
+            Reducer::UpdateProfile { .. } => "update_profile",
         }
     }
 }
@@ -122,6 +602,46 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "ack_command_event" => Ok(__sdk::parse_reducer_args::<
+                ack_command_event_reducer::AckCommandEventArgs,
+            >("ack_command_event", &value.args)?
+            .into()),
+            "apply_retention" => Ok(__sdk::parse_reducer_args::<
+                apply_retention_reducer::ApplyRetentionArgs,
+            >("apply_retention", &value.args)?
+            .into()),
+            "begin_auth" => Ok(
+                __sdk::parse_reducer_args::<begin_auth_reducer::BeginAuthArgs>(
+                    "begin_auth",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "bot_login" => Ok(
+                __sdk::parse_reducer_args::<bot_login_reducer::BotLoginArgs>(
+                    "bot_login",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "cancel_reminder" => Ok(__sdk::parse_reducer_args::<
+                cancel_reminder_reducer::CancelReminderArgs,
+            >("cancel_reminder", &value.args)?
+            .into()),
+            "cancel_scheduled_message" => {
+                Ok(__sdk::parse_reducer_args::<
+                    cancel_scheduled_message_reducer::CancelScheduledMessageArgs,
+                >("cancel_scheduled_message", &value.args)?
+                .into())
+            }
+            "cancel_stream" => Ok(__sdk::parse_reducer_args::<
+                cancel_stream_reducer::CancelStreamArgs,
+            >("cancel_stream", &value.args)?
+            .into()),
+            "change_password" => Ok(__sdk::parse_reducer_args::<
+                change_password_reducer::ChangePasswordArgs,
+            >("change_password", &value.args)?
+            .into()),
             "client_connected" => Ok(__sdk::parse_reducer_args::<
                 client_connected_reducer::ClientConnectedArgs,
             >("client_connected", &value.args)?
@@ -130,6 +650,33 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 client_disconnected_reducer::ClientDisconnectedArgs,
             >("client_disconnected", &value.args)?
             .into()),
+            "create_bot" => Ok(
+                __sdk::parse_reducer_args::<create_bot_reducer::CreateBotArgs>(
+                    "create_bot",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "create_webhook" => Ok(__sdk::parse_reducer_args::<
+                create_webhook_reducer::CreateWebhookArgs,
+            >("create_webhook", &value.args)?
+            .into()),
+            "delete_account" => Ok(__sdk::parse_reducer_args::<
+                delete_account_reducer::DeleteAccountArgs,
+            >("delete_account", &value.args)?
+            .into()),
+            "delete_webhook" => Ok(__sdk::parse_reducer_args::<
+                delete_webhook_reducer::DeleteWebhookArgs,
+            >("delete_webhook", &value.args)?
+            .into()),
+            "delete_webhooks" => Ok(__sdk::parse_reducer_args::<
+                delete_webhooks_reducer::DeleteWebhooksArgs,
+            >("delete_webhooks", &value.args)?
+            .into()),
+            "dismiss_notification" => Ok(__sdk::parse_reducer_args::<
+                dismiss_notification_reducer::DismissNotificationArgs,
+            >("dismiss_notification", &value.args)?
+            .into()),
             "edit_message" => Ok(
                 __sdk::parse_reducer_args::<edit_message_reducer::EditMessageArgs>(
                     "edit_message",
@@ -137,6 +684,45 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "edit_scheduled_message" => Ok(__sdk::parse_reducer_args::<
+                edit_scheduled_message_reducer::EditScheduledMessageArgs,
+            >("edit_scheduled_message", &value.args)?
+            .into()),
+            "expire_command_event" => Ok(__sdk::parse_reducer_args::<
+                expire_command_event_reducer::ExpireCommandEventArgs,
+            >("expire_command_event", &value.args)?
+            .into()),
+            "expire_typing" => Ok(__sdk::parse_reducer_args::<
+                expire_typing_reducer::ExpireTypingArgs,
+            >("expire_typing", &value.args)?
+            .into()),
+            "fire_reminder" => Ok(__sdk::parse_reducer_args::<
+                fire_reminder_reducer::FireReminderArgs,
+            >("fire_reminder", &value.args)?
+            .into()),
+            "import_messages" => Ok(__sdk::parse_reducer_args::<
+                import_messages_reducer::ImportMessagesArgs,
+            >("import_messages", &value.args)?
+            .into()),
+            "invoke_command" => Ok(__sdk::parse_reducer_args::<
+                invoke_command_reducer::InvokeCommandArgs,
+            >("invoke_command", &value.args)?
+            .into()),
+            "issue_bot_token" => Ok(__sdk::parse_reducer_args::<
+                issue_bot_token_reducer::IssueBotTokenArgs,
+            >("issue_bot_token", &value.args)?
+            .into()),
+            "issue_reset_code" => Ok(__sdk::parse_reducer_args::<
+                issue_reset_code_reducer::IssueResetCodeArgs,
+            >("issue_reset_code", &value.args)?
+            .into()),
+            "kick_user" => Ok(
+                __sdk::parse_reducer_args::<kick_user_reducer::KickUserArgs>(
+                    "kick_user",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "login" => Ok(__sdk::parse_reducer_args::<login_reducer::LoginArgs>(
                 "login",
                 &value.args,
@@ -147,6 +733,28 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 &value.args,
             )?
             .into()),
+            "mark_read" => Ok(
+                __sdk::parse_reducer_args::<mark_read_reducer::MarkReadArgs>(
+                    "mark_read",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "pin_message" => Ok(
+                __sdk::parse_reducer_args::<pin_message_reducer::PinMessageArgs>(
+                    "pin_message",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "post_scheduled_message" => Ok(__sdk::parse_reducer_args::<
+                post_scheduled_message_reducer::PostScheduledMessageArgs,
+            >("post_scheduled_message", &value.args)?
+            .into()),
+            "register_command" => Ok(__sdk::parse_reducer_args::<
+                register_command_reducer::RegisterCommandArgs,
+            >("register_command", &value.args)?
+            .into()),
             "remove_message" => Ok(__sdk::parse_reducer_args::<
                 remove_message_reducer::RemoveMessageArgs,
             >("remove_message", &value.args)?
@@ -155,6 +763,18 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 request_stream_reducer::RequestStreamArgs,
             >("request_stream", &value.args)?
             .into()),
+            "reset_password" => Ok(__sdk::parse_reducer_args::<
+                reset_password_reducer::ResetPasswordArgs,
+            >("reset_password", &value.args)?
+            .into()),
+            "revoke_bot_token" => Ok(__sdk::parse_reducer_args::<
+                revoke_bot_token_reducer::RevokeBotTokenArgs,
+            >("revoke_bot_token", &value.args)?
+            .into()),
+            "schedule_message" => Ok(__sdk::parse_reducer_args::<
+                schedule_message_reducer::ScheduleMessageArgs,
+            >("schedule_message", &value.args)?
+            .into()),
             "send_message" => Ok(
                 __sdk::parse_reducer_args::<send_message_reducer::SendMessageArgs>(
                     "send_message",
@@ -180,11 +800,73 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "set_presence" => Ok(
+                __sdk::parse_reducer_args::<set_presence_reducer::SetPresenceArgs>(
+                    "set_presence",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_purge_deleted_messages" => {
+                Ok(__sdk::parse_reducer_args::<
+                    set_purge_deleted_messages_reducer::SetPurgeDeletedMessagesArgs,
+                >("set_purge_deleted_messages", &value.args)?
+                .into())
+            }
+            "set_rate_limit" => Ok(__sdk::parse_reducer_args::<
+                set_rate_limit_reducer::SetRateLimitArgs,
+            >("set_rate_limit", &value.args)?
+            .into()),
+            "set_read_receipts" => Ok(__sdk::parse_reducer_args::<
+                set_read_receipts_reducer::SetReadReceiptsArgs,
+            >("set_read_receipts", &value.args)?
+            .into()),
+            "set_reminder" => Ok(
+                __sdk::parse_reducer_args::<set_reminder_reducer::SetReminderArgs>(
+                    "set_reminder",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_retention" => Ok(__sdk::parse_reducer_args::<
+                set_retention_reducer::SetRetentionArgs,
+            >("set_retention", &value.args)?
+            .into()),
+            "set_topic" => Ok(
+                __sdk::parse_reducer_args::<set_topic_reducer::SetTopicArgs>(
+                    "set_topic",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_typing" => Ok(
+                __sdk::parse_reducer_args::<set_typing_reducer::SetTypingArgs>(
+                    "set_typing",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "signup" => Ok(__sdk::parse_reducer_args::<signup_reducer::SignupArgs>(
                 "signup",
                 &value.args,
             )?
             .into()),
+            "snooze_notification" => Ok(__sdk::parse_reducer_args::<
+                snooze_notification_reducer::SnoozeNotificationArgs,
+            >("snooze_notification", &value.args)?
+            .into()),
+            "unpin_message" => Ok(__sdk::parse_reducer_args::<
+                unpin_message_reducer::UnpinMessageArgs,
+            >("unpin_message", &value.args)?
+            .into()),
+            "unregister_command" => Ok(__sdk::parse_reducer_args::<
+                unregister_command_reducer::UnregisterCommandArgs,
+            >("unregister_command", &value.args)?
+            .into()),
+            "update_profile" => Ok(__sdk::parse_reducer_args::<
+                update_profile_reducer::UpdateProfileArgs,
+            >("update_profile", &value.args)?
+            .into()),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -199,14 +881,39 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    archived_message: __sdk::TableUpdate<ArchivedMessage>,
+    auth_attempt: __sdk::TableUpdate<AuthAttempt>,
+    avatar: __sdk::TableUpdate<Avatar>,
+    bot_token: __sdk::TableUpdate<BotToken>,
+    command_event: __sdk::TableUpdate<CommandEvent>,
+    command_event_expiry: __sdk::TableUpdate<CommandEventExpiry>,
+    config: __sdk::TableUpdate<Config>,
     credentials: __sdk::TableUpdate<UserCredentials>,
     file: __sdk::TableUpdate<File>,
+    import_ref: __sdk::TableUpdate<ImportRef>,
+    kick: __sdk::TableUpdate<Kick>,
     message: __sdk::TableUpdate<Message>,
+    notification: __sdk::TableUpdate<Notification>,
+    password_reset: __sdk::TableUpdate<PasswordReset>,
+    pin: __sdk::TableUpdate<Pin>,
+    rate_bucket: __sdk::TableUpdate<RateBucket>,
+    rate_limit: __sdk::TableUpdate<RateLimit>,
+    read_state: __sdk::TableUpdate<ReadState>,
+    reminder: __sdk::TableUpdate<Reminder>,
     request: __sdk::TableUpdate<FileRequest>,
+    retention_job: __sdk::TableUpdate<RetentionJob>,
+    retention_run: __sdk::TableUpdate<RetentionRun>,
     room: __sdk::TableUpdate<VoiceRoom>,
+    scheduled_message: __sdk::TableUpdate<ScheduledMessage>,
+    session: __sdk::TableUpdate<Session>,
+    slash_command: __sdk::TableUpdate<SlashCommand>,
     temp_file: __sdk::TableUpdate<TempFile>,
+    thumbnail: __sdk::TableUpdate<Thumbnail>,
+    typing: __sdk::TableUpdate<Typing>,
+    typing_expiry: __sdk::TableUpdate<TypingExpiry>,
     user: __sdk::TableUpdate<User>,
     voice_packet: __sdk::TableUpdate<VoicePacket>,
+    webhook: __sdk::TableUpdate<Webhook>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -215,20 +922,85 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "archived_message" => {
+                    db_update.archived_message =
+                        archived_message_table::parse_table_update(table_update)?
+                }
+                "auth_attempt" => {
+                    db_update.auth_attempt = auth_attempt_table::parse_table_update(table_update)?
+                }
+                "avatar" => db_update.avatar = avatar_table::parse_table_update(table_update)?,
+                "bot_token" => {
+                    db_update.bot_token = bot_token_table::parse_table_update(table_update)?
+                }
+                "command_event" => {
+                    db_update.command_event = command_event_table::parse_table_update(table_update)?
+                }
+                "command_event_expiry" => {
+                    db_update.command_event_expiry =
+                        command_event_expiry_table::parse_table_update(table_update)?
+                }
+                "config" => db_update.config = config_table::parse_table_update(table_update)?,
                 "credentials" => {
                     db_update.credentials = credentials_table::parse_table_update(table_update)?
                 }
                 "file" => db_update.file = file_table::parse_table_update(table_update)?,
+                "import_ref" => {
+                    db_update.import_ref = import_ref_table::parse_table_update(table_update)?
+                }
+                "kick" => db_update.kick = kick_table::parse_table_update(table_update)?,
                 "message" => db_update.message = message_table::parse_table_update(table_update)?,
+                "notification" => {
+                    db_update.notification = notification_table::parse_table_update(table_update)?
+                }
+                "password_reset" => {
+                    db_update.password_reset =
+                        password_reset_table::parse_table_update(table_update)?
+                }
+                "pin" => db_update.pin = pin_table::parse_table_update(table_update)?,
+                "rate_bucket" => {
+                    db_update.rate_bucket = rate_bucket_table::parse_table_update(table_update)?
+                }
+                "rate_limit" => {
+                    db_update.rate_limit = rate_limit_table::parse_table_update(table_update)?
+                }
+                "read_state" => {
+                    db_update.read_state = read_state_table::parse_table_update(table_update)?
+                }
+                "reminder" => {
+                    db_update.reminder = reminder_table::parse_table_update(table_update)?
+                }
                 "request" => db_update.request = request_table::parse_table_update(table_update)?,
+                "retention_job" => {
+                    db_update.retention_job = retention_job_table::parse_table_update(table_update)?
+                }
+                "retention_run" => {
+                    db_update.retention_run = retention_run_table::parse_table_update(table_update)?
+                }
                 "room" => db_update.room = room_table::parse_table_update(table_update)?,
+                "scheduled_message" => {
+                    db_update.scheduled_message =
+                        scheduled_message_table::parse_table_update(table_update)?
+                }
+                "session" => db_update.session = session_table::parse_table_update(table_update)?,
+                "slash_command" => {
+                    db_update.slash_command = slash_command_table::parse_table_update(table_update)?
+                }
                 "temp_file" => {
                     db_update.temp_file = temp_file_table::parse_table_update(table_update)?
                 }
+                "thumbnail" => {
+                    db_update.thumbnail = thumbnail_table::parse_table_update(table_update)?
+                }
+                "typing" => db_update.typing = typing_table::parse_table_update(table_update)?,
+                "typing_expiry" => {
+                    db_update.typing_expiry = typing_expiry_table::parse_table_update(table_update)?
+                }
                 "user" => db_update.user = user_table::parse_table_update(table_update)?,
                 "voice_packet" => {
                     db_update.voice_packet = voice_packet_table::parse_table_update(table_update)?
                 }
+                "webhook" => db_update.webhook = webhook_table::parse_table_update(table_update)?,
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -255,29 +1027,107 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.archived_message = cache
+            .apply_diff_to_table::<ArchivedMessage>("archived_message", &self.archived_message)
+            .with_updates_by_pk(|row| &row.id);
+        diff.auth_attempt = cache
+            .apply_diff_to_table::<AuthAttempt>("auth_attempt", &self.auth_attempt)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.avatar = cache
+            .apply_diff_to_table::<Avatar>("avatar", &self.avatar)
+            .with_updates_by_pk(|row| &row.user_id);
+        diff.bot_token = cache
+            .apply_diff_to_table::<BotToken>("bot_token", &self.bot_token)
+            .with_updates_by_pk(|row| &row.bot_id);
+        diff.command_event = cache
+            .apply_diff_to_table::<CommandEvent>("command_event", &self.command_event)
+            .with_updates_by_pk(|row| &row.id);
+        diff.command_event_expiry = cache
+            .apply_diff_to_table::<CommandEventExpiry>(
+                "command_event_expiry",
+                &self.command_event_expiry,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.config = cache
+            .apply_diff_to_table::<Config>("config", &self.config)
+            .with_updates_by_pk(|row| &row.id);
         diff.credentials = cache
             .apply_diff_to_table::<UserCredentials>("credentials", &self.credentials)
             .with_updates_by_pk(|row| &row.user_id);
         diff.file = cache
             .apply_diff_to_table::<File>("file", &self.file)
             .with_updates_by_pk(|row| &row.id);
+        diff.import_ref = cache
+            .apply_diff_to_table::<ImportRef>("import_ref", &self.import_ref)
+            .with_updates_by_pk(|row| &row.key);
+        diff.kick = cache
+            .apply_diff_to_table::<Kick>("kick", &self.kick)
+            .with_updates_by_pk(|row| &row.user_id);
         diff.message = cache
             .apply_diff_to_table::<Message>("message", &self.message)
             .with_updates_by_pk(|row| &row.id);
+        diff.notification = cache
+            .apply_diff_to_table::<Notification>("notification", &self.notification)
+            .with_updates_by_pk(|row| &row.id);
+        diff.password_reset = cache
+            .apply_diff_to_table::<PasswordReset>("password_reset", &self.password_reset)
+            .with_updates_by_pk(|row| &row.user_id);
+        diff.pin = cache
+            .apply_diff_to_table::<Pin>("pin", &self.pin)
+            .with_updates_by_pk(|row| &row.message_id);
+        diff.rate_bucket = cache
+            .apply_diff_to_table::<RateBucket>("rate_bucket", &self.rate_bucket)
+            .with_updates_by_pk(|row| &row.key);
+        diff.rate_limit = cache
+            .apply_diff_to_table::<RateLimit>("rate_limit", &self.rate_limit)
+            .with_updates_by_pk(|row| &row.reducer);
+        diff.read_state = cache
+            .apply_diff_to_table::<ReadState>("read_state", &self.read_state)
+            .with_updates_by_pk(|row| &row.user_id);
+        diff.reminder = cache
+            .apply_diff_to_table::<Reminder>("reminder", &self.reminder)
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.request = cache
             .apply_diff_to_table::<FileRequest>("request", &self.request)
             .with_updates_by_pk(|row| &row.sender);
+        diff.retention_job = cache
+            .apply_diff_to_table::<RetentionJob>("retention_job", &self.retention_job)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.retention_run = cache
+            .apply_diff_to_table::<RetentionRun>("retention_run", &self.retention_run)
+            .with_updates_by_pk(|row| &row.id);
         diff.room = cache
             .apply_diff_to_table::<VoiceRoom>("room", &self.room)
             .with_updates_by_pk(|row| &row.id);
+        diff.scheduled_message = cache
+            .apply_diff_to_table::<ScheduledMessage>("scheduled_message", &self.scheduled_message)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.session = cache
+            .apply_diff_to_table::<Session>("session", &self.session)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.slash_command = cache
+            .apply_diff_to_table::<SlashCommand>("slash_command", &self.slash_command)
+            .with_updates_by_pk(|row| &row.name);
         diff.temp_file = cache
             .apply_diff_to_table::<TempFile>("temp_file", &self.temp_file)
             .with_updates_by_pk(|row| &row.id);
+        diff.thumbnail = cache
+            .apply_diff_to_table::<Thumbnail>("thumbnail", &self.thumbnail)
+            .with_updates_by_pk(|row| &row.file_id);
+        diff.typing = cache
+            .apply_diff_to_table::<Typing>("typing", &self.typing)
+            .with_updates_by_pk(|row| &row.user_id);
+        diff.typing_expiry = cache
+            .apply_diff_to_table::<TypingExpiry>("typing_expiry", &self.typing_expiry)
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.user = cache
             .apply_diff_to_table::<User>("user", &self.user)
             .with_updates_by_pk(|row| &row.id);
         diff.voice_packet =
             cache.apply_diff_to_table::<VoicePacket>("voice_packet", &self.voice_packet);
+        diff.webhook = cache
+            .apply_diff_to_table::<Webhook>("webhook", &self.webhook)
+            .with_updates_by_pk(|row| &row.id);
 
         diff
     }
@@ -287,14 +1137,39 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    archived_message: __sdk::TableAppliedDiff<'r, ArchivedMessage>,
+    auth_attempt: __sdk::TableAppliedDiff<'r, AuthAttempt>,
+    avatar: __sdk::TableAppliedDiff<'r, Avatar>,
+    bot_token: __sdk::TableAppliedDiff<'r, BotToken>,
+    command_event: __sdk::TableAppliedDiff<'r, CommandEvent>,
+    command_event_expiry: __sdk::TableAppliedDiff<'r, CommandEventExpiry>,
+    config: __sdk::TableAppliedDiff<'r, Config>,
     credentials: __sdk::TableAppliedDiff<'r, UserCredentials>,
     file: __sdk::TableAppliedDiff<'r, File>,
+    import_ref: __sdk::TableAppliedDiff<'r, ImportRef>,
+    kick: __sdk::TableAppliedDiff<'r, Kick>,
     message: __sdk::TableAppliedDiff<'r, Message>,
+    notification: __sdk::TableAppliedDiff<'r, Notification>,
+    password_reset: __sdk::TableAppliedDiff<'r, PasswordReset>,
+    pin: __sdk::TableAppliedDiff<'r, Pin>,
+    rate_bucket: __sdk::TableAppliedDiff<'r, RateBucket>,
+    rate_limit: __sdk::TableAppliedDiff<'r, RateLimit>,
+    read_state: __sdk::TableAppliedDiff<'r, ReadState>,
+    reminder: __sdk::TableAppliedDiff<'r, Reminder>,
     request: __sdk::TableAppliedDiff<'r, FileRequest>,
+    retention_job: __sdk::TableAppliedDiff<'r, RetentionJob>,
+    retention_run: __sdk::TableAppliedDiff<'r, RetentionRun>,
     room: __sdk::TableAppliedDiff<'r, VoiceRoom>,
+    scheduled_message: __sdk::TableAppliedDiff<'r, ScheduledMessage>,
+    session: __sdk::TableAppliedDiff<'r, Session>,
+    slash_command: __sdk::TableAppliedDiff<'r, SlashCommand>,
     temp_file: __sdk::TableAppliedDiff<'r, TempFile>,
+    thumbnail: __sdk::TableAppliedDiff<'r, Thumbnail>,
+    typing: __sdk::TableAppliedDiff<'r, Typing>,
+    typing_expiry: __sdk::TableAppliedDiff<'r, TypingExpiry>,
     user: __sdk::TableAppliedDiff<'r, User>,
     voice_packet: __sdk::TableAppliedDiff<'r, VoicePacket>,
+    webhook: __sdk::TableAppliedDiff<'r, Webhook>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -307,22 +1182,91 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<ArchivedMessage>(
+            "archived_message",
+            &self.archived_message,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<AuthAttempt>(
+            "auth_attempt",
+            &self.auth_attempt,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Avatar>("avatar", &self.avatar, event);
+        callbacks.invoke_table_row_callbacks::<BotToken>("bot_token", &self.bot_token, event);
+        callbacks.invoke_table_row_callbacks::<CommandEvent>(
+            "command_event",
+            &self.command_event,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<CommandEventExpiry>(
+            "command_event_expiry",
+            &self.command_event_expiry,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Config>("config", &self.config, event);
         callbacks.invoke_table_row_callbacks::<UserCredentials>(
             "credentials",
             &self.credentials,
             event,
         );
         callbacks.invoke_table_row_callbacks::<File>("file", &self.file, event);
+        callbacks.invoke_table_row_callbacks::<ImportRef>("import_ref", &self.import_ref, event);
+        callbacks.invoke_table_row_callbacks::<Kick>("kick", &self.kick, event);
         callbacks.invoke_table_row_callbacks::<Message>("message", &self.message, event);
+        callbacks.invoke_table_row_callbacks::<Notification>(
+            "notification",
+            &self.notification,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PasswordReset>(
+            "password_reset",
+            &self.password_reset,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Pin>("pin", &self.pin, event);
+        callbacks.invoke_table_row_callbacks::<RateBucket>("rate_bucket", &self.rate_bucket, event);
+        callbacks.invoke_table_row_callbacks::<RateLimit>("rate_limit", &self.rate_limit, event);
+        callbacks.invoke_table_row_callbacks::<ReadState>("read_state", &self.read_state, event);
+        callbacks.invoke_table_row_callbacks::<Reminder>("reminder", &self.reminder, event);
         callbacks.invoke_table_row_callbacks::<FileRequest>("request", &self.request, event);
+        callbacks.invoke_table_row_callbacks::<RetentionJob>(
+            "retention_job",
+            &self.retention_job,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<RetentionRun>(
+            "retention_run",
+            &self.retention_run,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<VoiceRoom>("room", &self.room, event);
+        callbacks.invoke_table_row_callbacks::<ScheduledMessage>(
+            "scheduled_message",
+            &self.scheduled_message,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Session>("session", &self.session, event);
+        callbacks.invoke_table_row_callbacks::<SlashCommand>(
+            "slash_command",
+            &self.slash_command,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<TempFile>("temp_file", &self.temp_file, event);
+        callbacks.invoke_table_row_callbacks::<Thumbnail>("thumbnail", &self.thumbnail, event);
+        callbacks.invoke_table_row_callbacks::<Typing>("typing", &self.typing, event);
+        callbacks.invoke_table_row_callbacks::<TypingExpiry>(
+            "typing_expiry",
+            &self.typing_expiry,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<User>("user", &self.user, event);
         callbacks.invoke_table_row_callbacks::<VoicePacket>(
             "voice_packet",
             &self.voice_packet,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Webhook>("webhook", &self.webhook, event);
     }
 }
 
@@ -898,13 +1842,38 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        archived_message_table::register_table(client_cache);
+        auth_attempt_table::register_table(client_cache);
+        avatar_table::register_table(client_cache);
+        bot_token_table::register_table(client_cache);
+        command_event_table::register_table(client_cache);
+        command_event_expiry_table::register_table(client_cache);
+        config_table::register_table(client_cache);
         credentials_table::register_table(client_cache);
         file_table::register_table(client_cache);
+        import_ref_table::register_table(client_cache);
+        kick_table::register_table(client_cache);
         message_table::register_table(client_cache);
+        notification_table::register_table(client_cache);
+        password_reset_table::register_table(client_cache);
+        pin_table::register_table(client_cache);
+        rate_bucket_table::register_table(client_cache);
+        rate_limit_table::register_table(client_cache);
+        read_state_table::register_table(client_cache);
+        reminder_table::register_table(client_cache);
         request_table::register_table(client_cache);
+        retention_job_table::register_table(client_cache);
+        retention_run_table::register_table(client_cache);
         room_table::register_table(client_cache);
+        scheduled_message_table::register_table(client_cache);
+        session_table::register_table(client_cache);
+        slash_command_table::register_table(client_cache);
         temp_file_table::register_table(client_cache);
+        thumbnail_table::register_table(client_cache);
+        typing_table::register_table(client_cache);
+        typing_expiry_table::register_table(client_cache);
         user_table::register_table(client_cache);
         voice_packet_table::register_table(client_cache);
+        webhook_table::register_table(client_cache);
     }
 }
@@ -4,12 +4,15 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::presence_type::Presence;
+
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub struct UserCredentials {
     pub user_id: u32,
     pub password: String,
     pub connections: Vec<__sdk::Identity>,
+    pub presence: Presence,
 }
 
 impl __sdk::InModule for UserCredentials {
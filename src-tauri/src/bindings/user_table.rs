@@ -2,6 +2,8 @@
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
 #![allow(unused, clippy::all)]
+use super::presence_type::Presence;
+use super::profile_type::Profile;
 use super::user_type::User;
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
@@ -4,14 +4,21 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::presence_type::Presence;
+use super::profile_type::Profile;
+
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub struct User {
     pub id: u32,
     pub is_admin: bool,
-    pub avatar: Option<Vec<u8>>,
+    pub is_bot: bool,
+    pub avatar_hash: Option<u64>,
     pub name: String,
-    pub online: Vec<__sdk::Identity>,
+    pub presence: Presence,
+    pub last_seen: Option<__sdk::Timestamp>,
+    pub deleted: bool,
+    pub profile: Profile,
 }
 
 impl __sdk::InModule for User {
@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+/// Built-in commands: name, argument hint, description
+pub const BUILTIN: [(&str, &str, &str); 6] = [
+    ("me", "<action>", "Send action in third person"),
+    ("shrug", "[text]", "Append ¯\\_(ツ)_/¯ to message"),
+    ("topic", "<topic>", "Set chat topic, admins only"),
+    ("nick", "<name>", "Change display name"),
+    ("remind", "<20m | 2h | 1d>", "Remind about replied or last message"),
+    ("kick", "<@user>", "Disconnect user for 10 minutes, admins only"),
+];
+
+/// Longest reminder, later time is likely a typo
+const MAX_DURATION: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+pub enum Command {
+    Me(String),
+    Shrug(String),
+    Topic(String),
+    Nick(String),
+    Remind(Duration),
+    Kick(String),
+    // Registered by bot, sent to it as event
+    Bot { name: String, args: String },
+}
+
+pub enum Input {
+    Message(String),
+    Command(Command),
+}
+
+/// `20m`, `2h`, `1d`, at most `MAX_DURATION`
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let unit = value.chars().last()?;
+    let amount = value[..value.len() - unit.len_utf8()].parse::<u64>().ok()?;
+
+    let seconds = match unit {
+        'm' => 60,
+        'h' => 60 * 60,
+        'd' => 24 * 60 * 60,
+        _ => return None,
+    };
+    let duration = Duration::from_secs(amount.checked_mul(seconds)?);
+    (amount > 0 && duration <= MAX_DURATION).then_some(duration)
+}
+
+fn required(name: &str, args: &str) -> Result<String, String> {
+    if args.is_empty() {
+        let hint = BUILTIN.iter().find(|(n, ..)| *n == name).map_or("", |(_, hint, _)| *hint);
+        return Err(format!("Usage: /{} {}", name, hint));
+    }
+
+    Ok(args.to_string())
+}
+
+/// Parse message box text, `//text` is sent as `/text` message
+pub fn parse(text: &str) -> Result<Input, String> {
+    if let Some(escaped) = text.strip_prefix("//") {
+        return Ok(Input::Message(format!("/{}", escaped)));
+    }
+
+    let Some(command) = text.strip_prefix('/') else {
+        return Ok(Input::Message(text.to_string()));
+    };
+
+    let (name, args) = command.split_once(char::is_whitespace).unwrap_or((command, ""));
+    let (name, args) = (name.to_lowercase(), args.trim());
+
+    let command = match name.as_str() {
+        "me" => Command::Me(required("me", args)?),
+        "shrug" => Command::Shrug(args.to_string()),
+        "topic" => Command::Topic(args.to_string()),
+        "nick" => Command::Nick(required("nick", args)?),
+        "remind" => match parse_duration(args) {
+            Some(duration) => Command::Remind(duration),
+            None => return Err(format!("Usage: /remind {}", BUILTIN[4].1)),
+        },
+        "kick" => Command::Kick(required("kick", args)?.trim_start_matches('@').to_string()),
+        "" => return Err("Empty command".to_string()),
+        _ => Command::Bot { name, args: args.to_string() },
+    };
+
+    Ok(Input::Command(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(text: &str) -> Command {
+        match parse(text) {
+            Ok(Input::Command(command)) => command,
+            _ => panic!("{} is not a command", text),
+        }
+    }
+
+    fn message(text: &str) -> String {
+        match parse(text) {
+            Ok(Input::Message(message)) => message,
+            _ => panic!("{} is not a message", text),
+        }
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("20m"), Some(Duration::from_secs(20 * 60)));
+        assert_eq!(parse_duration(" 2h "), Some(Duration::from_secs(2 * 60 * 60)));
+        assert_eq!(parse_duration("1d"), Some(Duration::from_secs(24 * 60 * 60)));
+        assert_eq!(parse_duration("365d"), Some(MAX_DURATION));
+
+        for value in ["", "m", "0m", "-1h", "10", "10s", "1.5h", "1д", "366d"] {
+            assert_eq!(parse_duration(value), None, "{}", value);
+        }
+    }
+
+    #[test]
+    fn rejects_overflowing_durations() {
+        assert_eq!(parse_duration("99999999999999999d"), None);
+        assert_eq!(parse_duration(&format!("{}m", u64::MAX)), None);
+        assert_eq!(parse_duration("99999999999999999999999d"), None);
+        assert!(parse("/remind 99999999999999999d").is_err());
+    }
+
+    #[test]
+    fn parses_messages() {
+        assert_eq!(message("hello"), "hello");
+        assert_eq!(message("//remind me"), "/remind me");
+        assert_eq!(message(" /me"), " /me");
+    }
+
+    #[test]
+    fn parses_builtin_commands() {
+        assert!(matches!(command("/me waves"), Command::Me(action) if action == "waves"));
+        assert!(matches!(command("/ME  waves "), Command::Me(action) if action == "waves"));
+        assert!(matches!(command("/shrug"), Command::Shrug(text) if text.is_empty()));
+        assert!(matches!(command("/topic"), Command::Topic(topic) if topic.is_empty()));
+        assert!(matches!(command("/nick Bob"), Command::Nick(name) if name == "Bob"));
+        assert!(matches!(command("/remind 2h"), Command::Remind(d) if d == Duration::from_secs(2 * 60 * 60)));
+        assert!(matches!(command("/kick @bob"), Command::Kick(name) if name == "bob"));
+    }
+
+    #[test]
+    fn parses_bot_commands() {
+        assert!(matches!(
+            command("/Deploy api prod"),
+            Command::Bot { name, args } if name == "deploy" && args == "api prod"
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_commands() {
+        assert_eq!(parse("/me").err().as_deref(), Some("Usage: /me <action>"));
+        assert_eq!(parse("/nick  ").err().as_deref(), Some("Usage: /nick <name>"));
+        assert_eq!(parse("/remind soon").err().as_deref(), Some("Usage: /remind <20m | 2h | 1d>"));
+        assert!(parse("/kick").is_err());
+        assert!(parse("/").is_err());
+    }
+}
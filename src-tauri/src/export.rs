@@ -0,0 +1,271 @@
+use std::{collections::HashMap, io::Write, path::Path};
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+use crate::bindings::{Avatar, File, Message, User};
+use crate::markdown::escape;
+use crate::{download_path, FileRefPayload, MessagePayload, UserPayload};
+
+fn zip_error(error: impl std::fmt::Display) -> String {
+    format!("Export error: {}", error)
+}
+
+/// Write personal data archive:
+/// profile, messages and uploaded files as json and raw files in `files/`
+pub fn write_user_data(
+    path: &Path,
+    user: User,
+    messages: Vec<Message>,
+    files: Vec<File>,
+) -> Result<(), String> {
+    let output = std::fs::File::create(path).map_err(zip_error)?;
+    let mut zip = ZipWriter::new(output);
+    let options = SimpleFileOptions::default();
+
+    let file_refs = messages
+        .iter()
+        .filter_map(|m| m.file.clone())
+        .map(FileRefPayload::new)
+        .collect::<Vec<_>>();
+
+    let messages = messages
+        .into_iter()
+        .map(MessagePayload::new)
+        .collect::<Vec<_>>();
+
+    let entries = [
+        ("profile.json", serde_json::to_vec_pretty(&UserPayload::new(user))),
+        ("messages.json", serde_json::to_vec_pretty(&messages)),
+        ("files.json", serde_json::to_vec_pretty(&file_refs)),
+    ];
+
+    for (name, data) in entries {
+        zip.start_file(name, options).map_err(zip_error)?;
+        zip.write_all(&data.map_err(zip_error)?).map_err(zip_error)?;
+    }
+
+    for file in files {
+        zip.start_file(format!("files/{}_{}", file.id, file.name), options)
+            .map_err(zip_error)?;
+        zip.write_all(&file.data).map_err(zip_error)?;
+    }
+
+    zip.finish().map_err(zip_error)?;
+    Ok(())
+}
+
+/// Inline style of exported html page
+const HISTORY_STYLE: &str = "\
+body { font-family: sans-serif; background: #202329; color: #eee; max-width: 900px; margin: auto; }
+.message { display: flex; gap: 12px; padding: 8px 0; border-bottom: 1px solid #333; }
+.avatar { width: 40px; height: 40px; min-width: 40px; border-radius: 50%; background: #6b8afd; text-align: center; line-height: 40px; }
+.header span { color: #888; font-size: 12px; }
+.reply { display: block; font-size: 12px; color: #6b8afd; }
+.mention { color: #6b8afd; }
+.file { max-width: 400px; max-height: 300px; display: block; }
+a { color: #6b8afd; }";
+
+/// Messages of conversation with their senders and avatars
+pub struct History {
+    pub title: String,
+    pub users: Vec<User>,
+    pub messages: Vec<Message>,
+    pub avatars: Vec<Avatar>,
+}
+
+#[derive(serde::Serialize)]
+struct HistoryJson {
+    title: String,
+    users: Vec<UserPayload>,
+    messages: Vec<MessagePayload>,
+}
+
+/// `YYYY-MM-DD HH:MM` in UTC from unix ms
+fn format_time(ms: u128) -> String {
+    let minutes = (ms / 60_000) as i64;
+    let days = minutes.div_euclid(24 * 60);
+    let (hour, minute) = (minutes.rem_euclid(24 * 60) / 60, minutes % 60);
+
+    // Civil date from days since epoch
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, hour, minute)
+}
+
+/// Percent-encode archive path for html links
+fn url_encode(path: &str) -> String {
+    let mut result = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => result.push(byte as char),
+            byte => result += &format!("%{:02X}", byte),
+        }
+    }
+    result
+}
+
+fn attachment_path(file: &FileRefPayload) -> String {
+    format!("files/{}_{}", file.id, file.name)
+}
+
+/// Archive path of avatar, extension by server encoding
+fn avatar_path(avatar: &Avatar) -> String {
+    let extension = if avatar.large.starts_with(b"\x89PNG") { "png" } else { "jpg" };
+    format!("avatars/{}.{}", avatar.user_id, extension)
+}
+
+fn history_markdown(title: &str, names: &HashMap<u32, String>, messages: &[MessagePayload]) -> String {
+    let mut result = format!("# {}\n", title);
+
+    for message in messages {
+        let name = names.get(&message.sender).map_or("-", |n| n.as_str());
+        result += &format!("\n**{}** · {} UTC", name, format_time(message.sent));
+        if message.edited.is_some() {
+            result += " (edited)";
+        }
+        result += "\n\n";
+
+        if let Some(reply) = message.reply {
+            result += &format!("> In reply to message {}\n\n", reply);
+        }
+        if !message.text.is_empty() {
+            result += &format!("{}\n\n", message.text);
+        }
+        if let Some(file) = &message.file {
+            result += &format!("[{}]({})\n\n", file.name, url_encode(&attachment_path(file)));
+        }
+        result += "---\n";
+    }
+
+    result
+}
+
+fn history_html(
+    title: &str,
+    users: &HashMap<u32, (String, Option<String>)>,
+    messages: &[MessagePayload],
+) -> String {
+    let mut body = String::new();
+
+    for message in messages {
+        let (name, avatar) = users.get(&message.sender).cloned().unwrap_or(("-".to_string(), None));
+        let avatar = match avatar {
+            Some(path) => format!("<img class=\"avatar\" src=\"{}\">", url_encode(&path)),
+            None => format!("<div class=\"avatar\">{}</div>", escape(&name.chars().take(1).collect::<String>())),
+        };
+
+        let mut content = message.html.clone();
+        if let Some(reply) = message.reply {
+            content = format!("<a class=\"reply\" href=\"#message-{}\">In reply</a>", reply) + &content;
+        }
+        if let Some(file) = &message.file {
+            let link = url_encode(&attachment_path(file));
+            content += &match file.mime.split('/').next() {
+                Some("image") => format!("<img class=\"file\" src=\"{}\">", link),
+                Some("audio") => format!("<audio controls src=\"{}\"></audio>", link),
+                Some("video") => format!("<video class=\"file\" controls src=\"{}\"></video>", link),
+                _ => format!("<a href=\"{}\">{}</a>", link, escape(&file.name)),
+            };
+        }
+
+        body += &format!(
+            "<div class=\"message\" id=\"message-{}\">{}<div><p class=\"header\"><b>{}</b> <span>{} UTC{}</span></p>{}</div></div>\n",
+            message.id,
+            avatar,
+            escape(&name),
+            format_time(message.sent),
+            if message.edited.is_some() { ", edited" } else { "" },
+            content,
+        );
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>{HISTORY_STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n{body}</body>\n</html>\n",
+        title = escape(title),
+    )
+}
+
+/// Write conversation history archive: json with full payloads, markdown and html
+/// transcripts, avatars and attachments. Attachments are read from download cache,
+/// `progress` is called with written and total entries
+pub fn write_history(
+    path: &Path,
+    history: History,
+    progress: impl Fn(usize, usize),
+) -> Result<(), String> {
+    let output = std::fs::File::create(path).map_err(zip_error)?;
+    let mut zip = ZipWriter::new(output);
+    let options = SimpleFileOptions::default();
+
+    let avatars = history
+        .avatars
+        .iter()
+        .map(|avatar| (avatar.user_id, avatar_path(avatar)))
+        .collect::<HashMap<_, _>>();
+
+    let users = history
+        .users
+        .iter()
+        .map(|user| (user.id, (user.profile.display_name.clone(), avatars.get(&user.id).cloned())))
+        .collect::<HashMap<_, _>>();
+    let names = users
+        .iter()
+        .map(|(id, (name, _))| (*id, name.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let messages = history
+        .messages
+        .into_iter()
+        .map(MessagePayload::new)
+        .collect::<Vec<_>>();
+    let files = messages.iter().filter_map(|m| m.file.clone()).collect::<Vec<_>>();
+
+    let total = 3 + history.avatars.len() + files.len();
+    let mut done = 0;
+
+    let markdown = history_markdown(&history.title, &names, &messages);
+    let html = history_html(&history.title, &users, &messages);
+    let json = HistoryJson {
+        title: history.title,
+        users: history.users.into_iter().map(UserPayload::new).collect(),
+        messages,
+    };
+
+    let entries = [
+        ("history.json", serde_json::to_vec_pretty(&json).map_err(zip_error)?),
+        ("history.md", markdown.into_bytes()),
+        ("index.html", html.into_bytes()),
+    ];
+
+    for (name, data) in entries {
+        zip.start_file(name, options).map_err(zip_error)?;
+        zip.write_all(&data).map_err(zip_error)?;
+        done += 1;
+        progress(done, total);
+    }
+
+    for avatar in &history.avatars {
+        zip.start_file(avatar_path(avatar), options).map_err(zip_error)?;
+        zip.write_all(&avatar.large).map_err(zip_error)?;
+        done += 1;
+        progress(done, total);
+    }
+
+    for file in &files {
+        let mut source = std::fs::File::open(download_path(file.id, &file.name)).map_err(zip_error)?;
+        zip.start_file(attachment_path(file), options).map_err(zip_error)?;
+        std::io::copy(&mut source, &mut zip).map_err(zip_error)?;
+        done += 1;
+        progress(done, total);
+    }
+
+    zip.finish().map_err(zip_error)?;
+    Ok(())
+}
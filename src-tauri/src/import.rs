@@ -0,0 +1,485 @@
+use std::{
+    collections::HashMap,
+    io::Read,
+    path::{Component, Path, PathBuf},
+};
+
+use serde::Deserialize;
+use spacetimedb_sdk::Timestamp;
+use zip::ZipArchive;
+
+use crate::bindings::{ImportedFile, ImportedMessage};
+
+/// Limits of one `import_messages` call
+const MAX_BATCH_MESSAGES: usize = 500;
+const MAX_BATCH_BYTES: usize = 4 * 1024 * 1024;
+
+fn import_error(error: impl std::fmt::Display) -> String {
+    format!("Import error: {}", error)
+}
+
+#[derive(Deserialize)]
+struct SlackUser {
+    id: String,
+    name: String,
+    #[serde(default)]
+    real_name: Option<String>,
+    #[serde(default)]
+    profile: Option<SlackProfile>,
+}
+
+#[derive(Deserialize, Default)]
+struct SlackProfile {
+    #[serde(default)]
+    display_name: String,
+    #[serde(default)]
+    real_name: String,
+}
+
+#[derive(Deserialize)]
+struct SlackChannel {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct SlackFile {
+    name: Option<String>,
+    url_private: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SlackMessage {
+    ts: String,
+    #[serde(default)]
+    user: Option<String>,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    subtype: Option<String>,
+    #[serde(default)]
+    thread_ts: Option<String>,
+    #[serde(default)]
+    user_profile: Option<SlackProfile>,
+    #[serde(default)]
+    files: Vec<SlackFile>,
+}
+
+/// Message subtypes with user content, others are join/leave and similar events
+const SLACK_CONTENT_SUBTYPES: [&str; 3] = ["thread_broadcast", "file_share", "me_message"];
+
+impl SlackUser {
+    fn display_name(&self) -> String {
+        let profile = self.profile.as_ref();
+        [
+            profile.map(|p| p.display_name.as_str()),
+            profile.map(|p| p.real_name.as_str()),
+            self.real_name.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|name| !name.trim().is_empty())
+        .unwrap_or(&self.name)
+        .to_string()
+    }
+}
+
+/// `1612345678.000200` seconds with micros
+fn slack_time(ts: &str) -> Option<Timestamp> {
+    let (seconds, micros) = ts.split_once('.').unwrap_or((ts, "0"));
+    let micros = format!("{:0<6}", micros).get(..6)?.parse::<i64>().ok()?;
+    Some(Timestamp::from_micros_since_unix_epoch(seconds.parse::<i64>().ok()? * 1_000_000 + micros))
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(zip: &mut ZipArchive<std::fs::File>, name: &str) -> Result<T, String> {
+    let mut data = String::new();
+    zip.by_name(name)
+        .map_err(import_error)?
+        .read_to_string(&mut data)
+        .map_err(import_error)?;
+    serde_json::from_str(&data).map_err(|e| import_error(format!("{}: {}", name, e)))
+}
+
+/// Read Slack workspace export: `users.json`, `channels.json` and `<channel>/<date>.json`.
+/// Attachments are not included in Slack exports, they are kept as links
+pub fn read_slack(path: &Path, channel: Option<&str>) -> Result<Vec<ImportedMessage>, String> {
+    let file = std::fs::File::open(path).map_err(import_error)?;
+    let mut zip = ZipArchive::new(file).map_err(import_error)?;
+
+    let users = read_json::<Vec<SlackUser>>(&mut zip, "users.json")?
+        .into_iter()
+        .map(|user| (user.id.clone(), user.display_name()))
+        .collect::<HashMap<_, _>>();
+
+    let channels = read_json::<Vec<SlackChannel>>(&mut zip, "channels.json")?
+        .into_iter()
+        .map(|c| c.name)
+        .filter(|name| channel.is_none_or(|channel| channel == name))
+        .collect::<Vec<_>>();
+
+    if channels.is_empty() {
+        return Err(import_error("channel not found"));
+    }
+
+    let names = zip.file_names().map(String::from).collect::<Vec<_>>();
+    let mut messages = Vec::new();
+    for channel in channels {
+        let prefix = format!("{}/", channel);
+        for name in names.iter().filter(|n| n.starts_with(&prefix) && n.ends_with(".json")) {
+            for message in read_json::<Vec<SlackMessage>>(&mut zip, name)? {
+                if message.subtype.as_deref().is_some_and(|s| !SLACK_CONTENT_SUBTYPES.contains(&s)) {
+                    continue;
+                }
+                let (Some(author), Some(sent)) = (message.user.clone(), slack_time(&message.ts)) else {
+                    continue;
+                };
+
+                let author_name = users
+                    .get(&author)
+                    .cloned()
+                    .or(message.user_profile.as_ref().map(|p| p.real_name.clone()))
+                    .unwrap_or_default();
+
+                // `<@U123>` mentions are shown with names
+                let mut text = message.text.clone();
+                for (id, name) in &users {
+                    text = text.replace(&format!("<@{}>", id), &format!("@{}", name));
+                }
+                for file in &message.files {
+                    let name = file.name.as_deref().unwrap_or("file");
+                    text += &match &file.url_private {
+                        Some(url) => format!("\n[{}]({})", name, url),
+                        None => format!("\nFile: {}", name),
+                    };
+                }
+
+                let reply = message
+                    .thread_ts
+                    .filter(|thread| thread != &message.ts)
+                    .map(|thread| format!("{}/{}", channel, thread));
+
+                messages.push(ImportedMessage {
+                    id: format!("{}/{}", channel, message.ts),
+                    author,
+                    author_name,
+                    sent,
+                    text,
+                    reply,
+                    file: None,
+                });
+            }
+        }
+    }
+
+    messages.sort_by_key(|m| m.sent);
+    Ok(messages)
+}
+
+#[derive(Deserialize)]
+struct DiscordExport {
+    messages: Vec<DiscordMessage>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiscordMessage {
+    id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    timestamp: String,
+    #[serde(default)]
+    content: String,
+    author: DiscordAuthor,
+    #[serde(default)]
+    attachments: Vec<DiscordAttachment>,
+    #[serde(default)]
+    reference: Option<DiscordReference>,
+}
+
+#[derive(Deserialize)]
+struct DiscordAuthor {
+    id: String,
+    name: String,
+    #[serde(default)]
+    nickname: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiscordAttachment {
+    url: String,
+    file_name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiscordReference {
+    #[serde(default)]
+    message_id: Option<String>,
+}
+
+/// Days since unix epoch of civil date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// `2021-03-04T05:06:07.123+00:00`
+fn rfc3339_time(value: &str) -> Option<Timestamp> {
+    let number = |range: std::ops::Range<usize>| value.get(range)?.parse::<i64>().ok();
+    let days = days_from_civil(number(0..4)?, number(5..7)?, number(8..10)?);
+    let seconds = days * 86400 + number(11..13)? * 3600 + number(14..16)? * 60 + number(17..19)?;
+
+    let rest = value.get(19..)?;
+    let zone_start = rest.find(['Z', '+', '-']).unwrap_or(rest.len());
+    let micros = match rest[..zone_start].strip_prefix('.') {
+        Some(fraction) => format!("{:0<6}", fraction).get(..6)?.parse::<i64>().ok()?,
+        None => 0,
+    };
+
+    let offset = match rest.get(zone_start..)? {
+        "" | "Z" => 0,
+        zone => {
+            let sign = if zone.starts_with('-') { -1 } else { 1 };
+            let hours = zone.get(1..3)?.parse::<i64>().ok()?;
+            let minutes = zone.get(4..6)?.parse::<i64>().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+
+    Some(Timestamp::from_micros_since_unix_epoch((seconds - offset) * 1_000_000 + micros))
+}
+
+/// Path of local attachment inside export directory.
+/// Urls, absolute paths and paths leaving the directory are rejected
+fn attachment_path(base: &Path, url: &str) -> Option<PathBuf> {
+    let relative = Path::new(url);
+    let is_relative = relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir | Component::ParentDir));
+    if url.contains(':') || !is_relative {
+        return None;
+    }
+
+    // Symlinks and `..` are resolved before the check
+    let base = base.canonicalize().ok()?;
+    let path = base.join(relative).canonicalize().ok()?;
+    path.starts_with(&base).then_some(path)
+}
+
+/// Local attachment of export made with media, path is relative to export file
+fn discord_attachment(base: &Path, attachment: &DiscordAttachment) -> Option<ImportedFile> {
+    let path = attachment_path(base, &attachment.url)?;
+    let data = std::fs::read(path).ok()?;
+    Some(ImportedFile { name: attachment.file_name.clone(), data })
+}
+
+/// Read DiscordChatExporter json export of one channel.
+/// First attachment exported with media is uploaded, others are kept as links
+pub fn read_discord(path: &Path) -> Result<Vec<ImportedMessage>, String> {
+    let data = std::fs::read_to_string(path).map_err(import_error)?;
+    let export: DiscordExport = serde_json::from_str(&data).map_err(import_error)?;
+    let base = path.parent().unwrap_or(Path::new("."));
+
+    let mut messages = Vec::new();
+    for message in export.messages {
+        if message.kind != "Default" && message.kind != "Reply" {
+            continue;
+        }
+        let Some(sent) = rfc3339_time(&message.timestamp) else {
+            continue;
+        };
+
+        let mut text = message.content;
+        let mut file = None;
+        for attachment in &message.attachments {
+            if file.is_none() {
+                file = discord_attachment(base, attachment);
+                if file.is_some() {
+                    continue;
+                }
+            }
+            text += &format!("\n[{}]({})", attachment.file_name, attachment.url);
+        }
+
+        messages.push(ImportedMessage {
+            id: message.id,
+            author: message.author.id,
+            author_name: message.author.nickname.unwrap_or(message.author.name),
+            sent,
+            text,
+            reply: message.reference.and_then(|r| r.message_id),
+            file,
+        });
+    }
+
+    messages.sort_by_key(|m| m.sent);
+    Ok(messages)
+}
+
+/// Split messages into reducer calls limited by count and size
+pub fn batches(messages: Vec<ImportedMessage>) -> Vec<Vec<ImportedMessage>> {
+    let mut batches = vec![];
+    let mut batch = vec![];
+    let mut size = 0;
+
+    for message in messages {
+        let message_size = message.text.len() + message.file.as_ref().map_or(0, |f| f.data.len());
+        if !batch.is_empty() && (batch.len() >= MAX_BATCH_MESSAGES || size + message_size > MAX_BATCH_BYTES) {
+            batches.push(std::mem::take(&mut batch));
+            size = 0;
+        }
+
+        size += message_size;
+        batch.push(message);
+    }
+
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use zip::{write::SimpleFileOptions, ZipWriter};
+
+    use super::*;
+
+    /// Empty directory of one test
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("chat-import-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn slack_export(dir: &Path, files: &[(&str, &str)]) -> PathBuf {
+        let path = dir.join("slack.zip");
+        let mut zip = ZipWriter::new(std::fs::File::create(&path).unwrap());
+        for (name, data) in files {
+            zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(data.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+        path
+    }
+
+    fn message(text: &str, file: Option<usize>) -> ImportedMessage {
+        ImportedMessage {
+            id: String::new(),
+            author: String::new(),
+            author_name: String::new(),
+            sent: Timestamp::from_micros_since_unix_epoch(0),
+            text: text.to_string(),
+            reply: None,
+            file: file.map(|size| ImportedFile { name: String::new(), data: vec![0; size] }),
+        }
+    }
+
+    #[test]
+    fn parses_times() {
+        let micros = |t: Option<Timestamp>| t.map(|t| t.to_micros_since_unix_epoch());
+
+        assert_eq!(micros(slack_time("1612345678.000200")), Some(1_612_345_678_000_200));
+        assert_eq!(micros(slack_time("1612345678")), Some(1_612_345_678_000_000));
+        assert_eq!(micros(slack_time("abc")), None);
+
+        assert_eq!(micros(rfc3339_time("2021-02-03T09:41:18.0002+00:00")), Some(1_612_345_278_000_200));
+        assert_eq!(micros(rfc3339_time("2021-02-03T11:41:18+02:00")), Some(1_612_345_278_000_000));
+        assert_eq!(micros(rfc3339_time("2021-02-03T09:41:18Z")), Some(1_612_345_278_000_000));
+        assert_eq!(micros(rfc3339_time("2021-02")), None);
+    }
+
+    #[test]
+    fn reads_slack_export() {
+        let dir = test_dir("slack");
+        let path = slack_export(&dir, &[
+            ("users.json", r#"[{"id": "U1", "name": "bob", "profile": {"display_name": "Bob"}}]"#),
+            ("channels.json", r#"[{"name": "general"}, {"name": "random"}]"#),
+            ("general/2021-02-03.json", r#"[
+                {"ts": "2.0", "user": "U1", "text": "reply to <@U1>", "thread_ts": "1.0"},
+                {"ts": "1.0", "user": "U1", "text": "hello", "files": [{"name": "a.png", "url_private": "https://files/a.png"}]},
+                {"ts": "3.0", "user": "U1", "text": "joined", "subtype": "channel_join"}
+            ]"#),
+            ("random/2021-02-03.json", r#"[{"ts": "4.0", "user": "U1", "text": "other"}]"#),
+        ]);
+
+        let messages = read_slack(&path, Some("general")).unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].id, "general/1.0");
+        assert_eq!(messages[0].author_name, "Bob");
+        assert_eq!(messages[0].text, "hello\n[a.png](https://files/a.png)");
+        assert_eq!(messages[1].text, "reply to @Bob");
+        assert_eq!(messages[1].reply.as_deref(), Some("general/1.0"));
+
+        assert_eq!(read_slack(&path, None).unwrap().len(), 3);
+        assert!(read_slack(&path, Some("missing")).is_err());
+    }
+
+    #[test]
+    fn reads_discord_export() {
+        let dir = test_dir("discord");
+        std::fs::create_dir(dir.join("media")).unwrap();
+        std::fs::write(dir.join("media/a.png"), b"image").unwrap();
+        let path = dir.join("export.json");
+        std::fs::write(&path, r#"{"messages": [
+            {"id": "2", "type": "Reply", "timestamp": "2021-02-03T09:41:19Z", "content": "hi",
+             "author": {"id": "A", "name": "alice", "nickname": "Alice"}, "reference": {"messageId": "1"}},
+            {"id": "1", "type": "Default", "timestamp": "2021-02-03T09:41:18Z", "content": "files",
+             "author": {"id": "A", "name": "alice"}, "attachments": [
+                {"url": "media/a.png", "fileName": "a.png"},
+                {"url": "https://cdn/b.png", "fileName": "b.png"}
+             ]},
+            {"id": "3", "type": "ChannelPinnedMessage", "timestamp": "2021-02-03T09:41:20Z",
+             "author": {"id": "A", "name": "alice"}}
+        ]}"#).unwrap();
+
+        let messages = read_discord(&path).unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].id, "1");
+        assert_eq!(messages[0].author_name, "alice");
+        assert_eq!(messages[0].text, "files\n[b.png](https://cdn/b.png)");
+        assert_eq!(messages[0].file.as_ref().map(|f| f.data.as_slice()), Some(&b"image"[..]));
+        assert_eq!(messages[1].author_name, "Alice");
+        assert_eq!(messages[1].reply.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn rejects_attachments_outside_export() {
+        let dir = test_dir("traversal");
+        let base = dir.join("export");
+        std::fs::create_dir(&base).unwrap();
+        std::fs::write(dir.join("secret"), b"secret").unwrap();
+        std::fs::write(base.join("file"), b"file").unwrap();
+
+        assert!(attachment_path(&base, "file").is_some());
+        assert!(attachment_path(&base, "./file").is_some());
+        assert!(attachment_path(&base, "../secret").is_none());
+        assert!(attachment_path(&base, "sub/../../secret").is_none());
+        assert!(attachment_path(&base, dir.join("secret").to_str().unwrap()).is_none());
+        assert!(attachment_path(&base, "file:///etc/passwd").is_none());
+        assert!(attachment_path(&base, "https://cdn/file").is_none());
+    }
+
+    #[test]
+    fn splits_batches() {
+        assert!(batches(vec![]).is_empty());
+
+        let sizes = |batches: Vec<Vec<ImportedMessage>>| batches.iter().map(Vec::len).collect::<Vec<_>>();
+        let messages = (0..MAX_BATCH_MESSAGES + 1).map(|_| message("text", None)).collect();
+        assert_eq!(sizes(batches(messages)), vec![MAX_BATCH_MESSAGES, 1]);
+
+        let messages = vec![message("a", Some(MAX_BATCH_BYTES - 10)), message("b", Some(20)), message("c", None)];
+        assert_eq!(sizes(batches(messages)), vec![1, 2]);
+
+        // Message larger than limit is sent alone
+        let messages = vec![message("a", Some(MAX_BATCH_BYTES + 1)), message("b", None)];
+        assert_eq!(sizes(batches(messages)), vec![1, 1]);
+    }
+}
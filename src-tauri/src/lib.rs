@@ -1,13 +1,22 @@
 use std::{
     thread,
     path::PathBuf,
+    collections::{HashMap, HashSet},
     sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 use cpal::traits::{DeviceTrait, HostTrait};
+use rand::Rng;
 use tauri::{AppHandle, Emitter, Manager, RunEvent, State};
 use tauri_plugin_updater::UpdaterExt;
 
-mod bindings;
+pub mod bindings;
+mod command;
+mod export;
+mod import;
+mod markdown;
+mod notify;
+mod protocol;
 use bindings::*;
 use spacetimedb_sdk::*;
 
@@ -53,6 +62,12 @@ pub struct FileRefPayload {
     pub id: u32,
     pub name: String,
     pub size: u64,
+    pub mime: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    // Custom scheme urls, file is downloaded on request
+    pub url: String,
+    pub thumbnail: Option<String>,
 }
 
 impl FileRefPayload {
@@ -61,10 +76,44 @@ impl FileRefPayload {
             id: file.id,
             name: file.name,
             size: file.size,
+            mime: file.mime,
+            width: file.image.as_ref().and_then(|i| Some(i.width)),
+            height: file.image.as_ref().and_then(|i| Some(i.height)),
+            url: protocol::file_url(file.id),
+            thumbnail: file.image.and_then(|_| Some(protocol::thumbnail_url(file.id))),
         }
     }
 }
 
+#[derive(Clone, serde::Serialize)]
+pub struct MentionPayload {
+    // user | here | everyone
+    pub kind: &'static str,
+    pub user_id: Option<u32>,
+    // UTF-16 offsets for webview
+    pub start: usize,
+    pub end: usize,
+}
+
+impl MentionPayload {
+    pub fn new(span: MentionSpan, text: &str) -> Option<Self> {
+        let utf16_offset = |index: u32| Some(text.get(..index as usize)?.encode_utf16().count());
+
+        let (kind, user_id) = match span.kind {
+            MentionKind::User(id) => ("user", Some(id)),
+            MentionKind::Here => ("here", None),
+            MentionKind::Everyone => ("everyone", None),
+        };
+
+        Some(Self {
+            kind,
+            user_id,
+            start: utf16_offset(span.start)?,
+            end: utf16_offset(span.end)?,
+        })
+    }
+}
+
 #[derive(Clone, serde::Serialize)]
 pub struct MessagePayload {
     pub id: u32,
@@ -73,7 +122,10 @@ pub struct MessagePayload {
     pub edited: Option<u128>,
     pub reply: Option<u32>,
     pub text: String,
+    // Rendered markdown, safe to insert into webview
+    pub html: String,
     pub file: Option<FileRefPayload>,
+    pub mentions: Vec<MentionPayload>,
 }
 
 impl MessagePayload {
@@ -92,43 +144,256 @@ impl MessagePayload {
             .file
             .and_then(|file| Some(FileRefPayload::new(file)));
 
+        let mut ranges = message
+            .mention_spans
+            .iter()
+            .map(|span| span.start as usize..span.end as usize)
+            .collect::<Vec<_>>();
+        ranges.sort_by_key(|range| range.start);
+        let html = markdown::render(&message.text, &ranges);
+
+        let mentions = message
+            .mention_spans
+            .into_iter()
+            .filter_map(|span| MentionPayload::new(span, &message.text))
+            .collect();
+
         Self {
             id: message.id,
             sender: message.sender,
             sent,
             edited,
             text: message.text,
+            html,
             reply: message.reply,
             file,
+            mentions,
+        }
+    }
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct CommandPayload {
+    pub name: String,
+    pub hint: String,
+    pub description: String,
+    // Display name of bot handling command, built-in if none
+    pub bot: Option<String>,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct ScheduledPayload {
+    pub id: u64,
+    pub text: String,
+    pub reply: Option<u32>,
+    pub at: u128,
+    pub has_file: bool,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct ReminderPayload {
+    pub id: u64,
+    pub message: MessagePayload,
+    pub at: u128,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct NotificationPayload {
+    pub id: u64,
+    pub message: MessagePayload,
+    pub created_at: u128,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct PinPayload {
+    pub message: MessagePayload,
+    pub pinned_by: u32,
+    pub pinned_at: u128,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct StatusPayload {
+    pub text: String,
+    pub emoji: Option<String>,
+    pub expires: Option<u128>,
+}
+
+impl StatusPayload {
+    /// Get status if it is not expired
+    pub fn new(status: UserStatus) -> Option<Self> {
+        let expires = status
+            .expires
+            .and_then(|time| Some(time.to_duration_since_unix_epoch().unwrap().as_millis()));
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+
+        if expires.is_some_and(|expires| expires <= now) {
+            return None;
+        }
+
+        Some(Self {
+            text: status.text,
+            emoji: status.emoji,
+            expires,
+        })
+    }
+
+    pub fn into_status(self) -> UserStatus {
+        let expires = self
+            .expires
+            .and_then(|ms| Some(Timestamp::from_micros_since_unix_epoch(ms as i64 * 1000)));
+
+        UserStatus {
+            text: self.text,
+            emoji: self.emoji,
+            expires,
+        }
+    }
+}
+
+/// Editable profile fields
+#[derive(Clone, serde::Deserialize)]
+pub struct ProfilePayload {
+    pub display_name: String,
+    pub status: Option<StatusPayload>,
+    pub bio: String,
+    pub pronouns: String,
+    pub timezone: String,
+}
+
+impl ProfilePayload {
+    pub fn into_profile(self) -> Profile {
+        Profile {
+            display_name: self.display_name,
+            status: self.status.and_then(|s| Some(s.into_status())),
+            bio: self.bio,
+            pronouns: self.pronouns,
+            timezone: self.timezone,
         }
     }
 }
 
+/// Inactivity time before online user becomes idle
+const IDLE_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// Min interval between typing updates, server keeps state for 5 s
+const TYPING_THROTTLE: Duration = Duration::from_secs(2);
+/// Delay to merge bursts of table changes into one event
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(300);
+
+fn presence_name(presence: Presence) -> &'static str {
+    match presence {
+        Presence::Offline => "offline",
+        Presence::Online => "online",
+        Presence::Idle => "idle",
+        Presence::DoNotDisturb => "dnd",
+        Presence::Invisible => "invisible",
+    }
+}
+
+fn parse_presence(name: &str) -> Option<Presence> {
+    match name {
+        "online" => Some(Presence::Online),
+        "idle" => Some(Presence::Idle),
+        "dnd" => Some(Presence::DoNotDisturb),
+        "invisible" => Some(Presence::Invisible),
+        _ => None,
+    }
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct UnreadPayload {
+    // First unread message by send time
+    pub first: Option<u32>,
+    pub unread: usize,
+    pub mentions: usize,
+}
+
+/// Read marker shared by user
+#[derive(Clone, serde::Serialize)]
+pub struct ReceiptPayload {
+    pub user: UserPayload,
+    // Send time of last read message, milliseconds like `MessagePayload::sent`
+    pub read_until: u128,
+}
+
 #[derive(Clone, serde::Serialize)]
 pub struct UserPayload {
     pub id: u32,
     pub name: String,
+    pub display_name: String,
     pub avatar: Option<String>,
+    pub avatar_small: Option<String>,
     pub is_admin: bool,
+    pub is_bot: bool,
     pub online: bool,
+    pub presence: &'static str,
+    pub last_seen: Option<u128>,
+    pub deleted: bool,
+    pub status: Option<StatusPayload>,
+    pub bio: String,
+    pub pronouns: String,
+    pub timezone: String,
 }
 
 impl UserPayload {
     pub fn new(user: User) -> Self {
-        let avatar = user.avatar
-            .and_then(|data| photon_rs::native::open_image_from_bytes(&data).ok())
-            .and_then(|image| Some(image.get_base64()));
-
+        let avatar = user
+            .avatar_hash
+            .and_then(|hash| Some(protocol::avatar_url(user.id, hash)));
+        let avatar_small = user
+            .avatar_hash
+            .map(|hash| protocol::small_avatar_url(user.id, hash));
+
+        let last_seen = user
+            .last_seen
+            .and_then(|time| Some(time.to_duration_since_unix_epoch().unwrap().as_millis()));
+
+        let profile = user.profile;
         Self {
             id: user.id,
             name: user.name,
+            display_name: profile.display_name,
             avatar,
+            avatar_small,
             is_admin: user.is_admin,
-            online: !user.online.is_empty(),
+            is_bot: user.is_bot,
+            online: user.presence != Presence::Offline,
+            presence: presence_name(user.presence),
+            last_seen,
+            deleted: user.deleted,
+            status: profile.status.and_then(StatusPayload::new),
+            bio: profile.bio,
+            pronouns: profile.pronouns,
+            timezone: profile.timezone,
         }
     }
 }
 
+/// Parse retry delay (ms) from rate limited reducer error
+fn retry_after(error: &str) -> Option<u64> {
+    error
+        .strip_prefix("Too many requests, retry after ")?
+        .strip_suffix(" ms")?
+        .parse()
+        .ok()
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct CooldownPayload {
+    pub reducer: String,
+    pub retry_after: u64,
+}
+
+impl CooldownPayload {
+    pub fn new(reducer: &str, retry_after: u64) -> Self {
+        Self { reducer: reducer.to_string(), retry_after }
+    }
+}
+
 #[derive(Clone, serde::Serialize)]
 pub struct SendPayload {
     pub ready: usize,
@@ -255,8 +520,29 @@ struct SessionInner {
     /// Window events emitter
     pub app: AppHandle,
     pub downloading: Vec<DownloadingFile>,
+    /// Files subscription of running data export
+    pub exporting: Option<SubscriptionHandle>,
+    /// History export is writing archive
+    pub exporting_history: bool,
+    /// Committed and total batches of running history import
+    pub importing: Option<(usize, usize)>,
+    /// Rate limited reducers, blocked until instant
+    pub cooldowns: HashMap<String, Instant>,
     pub connection: Option<DbConnection>,
     pub identity: Option<Identity>,
+    /// Logged in user
+    pub user_id: Option<u32>,
+    /// Presence chosen by user
+    pub presence: Presence,
+    /// Presence is set to idle after inactivity
+    pub idle: bool,
+    pub last_activity: Instant,
+    /// Last sent typing state
+    pub typing_sent: Option<Instant>,
+    /// Events waiting for debounce
+    pub debounced: HashSet<&'static str>,
+    pub notifier: notify::Notifier,
+    pub avatars: protocol::AvatarCache,
 }
 
 type SessionState = Arc<Mutex<SessionInner>>;
@@ -266,8 +552,20 @@ impl SessionInner {
         Self {
             app: handle.clone(),
             downloading: Vec::new(),
+            exporting: None,
+            exporting_history: false,
+            importing: None,
+            cooldowns: HashMap::new(),
             connection: None,
             identity: None,
+            user_id: None,
+            presence: Presence::Online,
+            idle: false,
+            last_activity: Instant::now(),
+            typing_sent: None,
+            debounced: HashSet::new(),
+            notifier: notify::Notifier::default(),
+            avatars: protocol::AvatarCache::default(),
         }
     }
 
@@ -290,408 +588,1990 @@ impl SessionInner {
             .collect()
     }
 
-    pub fn on_connect_error(&mut self, error: String) {
-        self.connection = None;
-        self.identity = None;
+    /// Get logged in user
+    pub fn current_user(&self) -> Option<User> {
+        let connection = self.connection.as_ref()?;
+        connection.db.user().id().find(&self.user_id?)
+    }
+
+    /// Own session is opened on login
+    pub fn on_session_insert(&mut self, session: &Session, user: Option<User>) {
+        if self.identity != Some(session.identity) {
+            return;
+        }
+        self.user_id = Some(session.user_id);
+
+        let Some(user) = user else {
+            return;
+        };
+
+        // Restore chosen presence, offline is invisible for logged in user
+        self.idle = false;
+        self.presence = match user.presence {
+            Presence::Offline => Presence::Invisible,
+            Presence::Idle => Presence::Online,
+            presence => presence,
+        };
 
         self.app
-            .emit("on_connect_error", error)
+            .emit("loginned", UserPayload::new(user))
             .expect("Emit error");
     }
 
-    pub fn on_disconnect(&mut self, error: Option<String>) {
-        self.connection = None;
-        self.identity = None;
+    pub fn on_session_removed(&mut self, session: &Session) {
+        if self.identity == Some(session.identity) {
+            self.user_id = None;
+        }
+    }
 
-        self.app.emit("on_disconnect", error).expect("Emit error");
+    fn send_presence(&self, presence: Presence) {
+        let Some(connection) = &self.connection else {
+            return;
+        };
+
+        connection
+            .reducers
+            .set_presence(presence)
+            .expect("Spacetime error");
     }
 
-    pub fn on_login_error(&mut self, error: String) {
-        self.app.emit("on_login_error", error).expect("Emit error");
+    pub fn set_presence(&mut self, presence: Presence) {
+        self.presence = presence;
+        self.idle = false;
+        self.last_activity = Instant::now();
+        self.send_presence(presence);
     }
 
-    pub fn on_user_insert(&mut self, user: &User) {
-        let identity = &self.identity.unwrap();
-        if user.online.contains(&identity) {
-            self.app
-                .emit("loginned", UserPayload::new(user.clone()))
-                .expect("Emit error");
+    pub fn report_activity(&mut self) {
+        self.last_activity = Instant::now();
+        if self.idle {
+            self.idle = false;
+            self.send_presence(self.presence);
         }
-
-        self.app.emit("user_inserted", UserPayload::new(user.clone())).expect("Emit error");
     }
 
-    pub fn on_user_removed(&mut self, user: &User) {
-        self.app.emit("user_removed", UserPayload::new(user.clone())).expect("Emit error");
-    }
+    pub fn set_typing(&mut self) {
+        let Some(connection) = &self.connection else {
+            return;
+        };
 
-    pub fn on_user_updated(&mut self, _old: &User, new: &User) {
-        let identity = &self.identity.unwrap();
-        if new.online.contains(&identity) {
-            self.app
-                .emit("loginned", UserPayload::new(new.clone()))
-                .expect("Emit error");
+        if self.typing_sent.is_some_and(|sent| sent.elapsed() < TYPING_THROTTLE) {
+            return;
         }
+        self.typing_sent = Some(Instant::now());
 
-        self.app.emit("user_updated", UserPayload::new(new.clone())).expect("Emit error");
+        connection.reducers.set_typing().expect("Spacetime error");
     }
 
-    pub fn on_message_insert(&mut self, message: &Message) {
-        self.app
-            .emit("message_inserted", MessagePayload::new(message.clone()))
-            .expect("Emit error");
-    }
+    fn is_read(&self, message: &Message) -> bool {
+        let Some(connection) = &self.connection else {
+            return false;
+        };
 
-    pub fn on_message_removed(&mut self, message: &Message) {
-        self.app.emit("message_removed", MessagePayload::new(message.clone())).expect("Emit error");
+        self.user_id
+            .and_then(|id| connection.db.read_state().user_id().find(&id))
+            .is_some_and(|state| message.sent <= state.read_until)
     }
 
-    pub fn on_message_updated(&mut self, new: &Message) {
-        self.app.emit("message_updated", MessagePayload::new(new.clone())).expect("Emit error");
+    /// Messages of other users after read marker, sorted by send time
+    fn unread_messages(&self) -> Vec<Message> {
+        let Some(connection) = &self.connection else {
+            return vec![];
+        };
+
+        let mut messages = connection
+            .db
+            .message()
+            .iter()
+            .filter(|m| Some(m.sender) != self.user_id && !self.is_read(m))
+            .collect::<Vec<_>>();
+
+        messages.sort_by_key(|m| m.sent);
+        messages
     }
 
-    /// Load file on inserted
-    pub fn on_file_inserted(&mut self, file: &File) {
-        let path = format!("./downloads/{}_{}", file.id, file.name);
+    pub fn on_unread_changed(&mut self) {
+        let Some(user) = self.current_user() else {
+            return;
+        };
 
-        std::fs::create_dir_all("./downloads/").expect("FS error");
-        std::fs::write(path, &file.data).expect("Write error");
-        let index = self
-            .downloading
-            .iter()
-            .enumerate()
-            .find(|(_, d)| d.file == file.id)
-            .and_then(|(i, _)| Some(i))
-            .unwrap();
+        let unread = self.unread_messages();
+        let payload = UnreadPayload {
+            first: unread.first().map(|m| m.id),
+            unread: unread.len(),
+            mentions: unread.iter().filter(|m| m.mentions.contains(&user.id)).count(),
+        };
 
-        let state = self.downloading.swap_remove(index);
-        state.subscription.unsubscribe().expect("Spacetime error");
-        self.downloading.retain(|d| d.file != file.id);
-        self.app.emit("on_file_downloaded", file.id).expect("Emit error");
+        self.app.emit("unread_changed", payload).expect("Emit error");
     }
 
-    pub fn download_file(
-        &mut self,
-        payload: FileRefPayload,
-        inner: SessionState,
-    ) -> Option<String> {
+    pub fn mark_read(&self, message_id: u32) {
         let Some(connection) = &self.connection else {
-            return None;
+            return;
         };
 
-        // If is downloading...
-        if self.downloading.iter()
-            .find(|d| d.file == payload.id).is_some() {
-            return None;
+        let Some(message) = connection.db.message().id().find(&message_id) else {
+            return;
+        };
+        if self.is_read(&message) {
+            return;
         }
 
-        if let Some(path) = file_path(payload.clone()) {
-            return Some(path);
-        }
-        
-        let subscription = connection
-            .subscription_builder()
-            .on_error(move |_ctx, _err| {
-                inner
-                    .lock()
-                    .unwrap()
-                    .downloading
-                    .retain(|d| d.file != payload.id);
-            })
-            .subscribe(format!("SELECT * from file f WHERE f.id = {}", payload.id));
+        connection
+            .reducers
+            .mark_read(message_id)
+            .expect("Spacetime error");
+    }
 
-        self.downloading.push(DownloadingFile {
-            file: payload.id,
-            subscription,
-        });
+    /// Read markers of users sharing receipts, messages find their readers in them
+    pub fn get_receipts(&self) -> Vec<ReceiptPayload> {
+        let Some(connection) = &self.connection else {
+            return vec![];
+        };
 
-        None
+        connection
+            .db
+            .read_state()
+            .iter()
+            .filter(|s| s.share_receipts)
+            .filter_map(|s| {
+                let user = connection.db.user().id().find(&s.user_id)?;
+                let read_until = s.read_until.to_duration_since_unix_epoch().unwrap().as_millis();
+                Some(ReceiptPayload { user: UserPayload::new(user), read_until })
+            })
+            .collect()
     }
 
-    pub fn on_send_packet(&mut self, lenght: usize, remain: usize) {
-        self.app
-            .emit("send_status", SendPayload::new(lenght - remain, lenght))
-            .expect("Emit error");
-    }
+    /// Users who shared read marker past the message
+    pub fn get_read_by(&self, message_id: u32) -> Vec<UserPayload> {
+        let Some(connection) = &self.connection else {
+            return vec![];
+        };
+        let Some(message) = connection.db.message().id().find(&message_id) else {
+            return vec![];
+        };
+        let sent = message.sent.to_duration_since_unix_epoch().unwrap().as_millis();
 
-    pub fn exit(&self) {
-        println!("Exit");
+        self.get_receipts()
+            .into_iter()
+            .filter(|r| r.read_until >= sent && r.user.id != message.sender)
+            .map(|r| r.user)
+            .collect()
+    }
 
+    pub fn read_receipts_enabled(&self) -> bool {
         let Some(connection) = &self.connection else {
-            return;
+            return true;
         };
 
-        match connection.disconnect() {
-            Err(e) => eprintln!("Disconnect error {}", e),
-            _ => (),
-        }
+        self.user_id
+            .and_then(|id| connection.db.read_state().user_id().find(&id))
+            .map_or(true, |s| s.share_receipts)
     }
-}
 
-fn register_callbacks(ctx: &DbConnection, session: SessionState, sending: SendingFileState) {
-    let inner = session.clone();
-    ctx.db.user().on_insert(move |_ctx, user| {
-        inner.lock().unwrap().on_user_insert(user);
-    });
-
-    let inner = session.clone();
-    ctx.db.message().on_insert(move |_ctx, message| {
-        inner.lock().unwrap().on_message_insert(message);
-    });
+    /// Built-in and bot commands for autocomplete
+    pub fn get_commands(&self) -> Vec<CommandPayload> {
+        let mut commands = command::BUILTIN
+            .iter()
+            .map(|(name, hint, description)| CommandPayload {
+                name: name.to_string(),
+                hint: hint.to_string(),
+                description: description.to_string(),
+                bot: None,
+            })
+            .collect::<Vec<_>>();
 
-    let inner = session.clone();
-    ctx.db.message().on_update(move |_ctx, _old, new| {
-        inner.lock().unwrap().on_message_updated(new);
-    });
+        let Some(connection) = &self.connection else {
+            return commands;
+        };
 
-    let inner = session.clone();
-    ctx.db.message().on_delete(move |_ctx, message| {
-        inner.lock().unwrap().on_message_removed(message);
-    });
+        let mut registered = connection
+            .db
+            .slash_command()
+            .iter()
+            .map(|command| CommandPayload {
+                bot: connection
+                    .db
+                    .user()
+                    .id()
+                    .find(&command.bot_id)
+                    .map(|bot| bot.profile.display_name),
+                name: command.name,
+                hint: command.hint,
+                description: command.description,
+            })
+            .collect::<Vec<_>>();
+        registered.sort_by(|a, b| a.name.cmp(&b.name));
 
-    let inner = session.clone();
-    ctx.db.user().on_update(move |_ctx, old, new| {
-        inner.lock().unwrap().on_user_updated(old, new);
-    });
+        commands.extend(registered);
+        commands
+    }
 
-    let inner = session.clone();
-    ctx.db.user().on_delete(move |_ctx, user| {
-        inner.lock().unwrap().on_user_removed(user);
-    });
+    pub fn get_topic(&self) -> String {
+        let Some(connection) = &self.connection else {
+            return String::new();
+        };
 
-    let inner = session.clone();
-    ctx.db.file().on_insert(move |_ctx, file| {
-        inner.lock().unwrap().on_file_inserted(file);
-    });
+        connection
+            .db
+            .config()
+            .id()
+            .find(&0)
+            .map_or(String::new(), |config| config.topic)
+    }
 
-    let inner = session.clone();
-    ctx.reducers.on_login(move |ctx, _name, _password| {
-        if let Status::Failed(err) = &ctx.event.status {
-            inner.lock().unwrap().on_login_error(err.to_string());
-        }
-    });
+    /// Run slash command, returns message text to send for text commands
+    pub fn run_command(
+        &self,
+        command: command::Command,
+        reply: Option<u32>,
+    ) -> std::result::Result<Option<String>, String> {
+        let Some(connection) = &self.connection else {
+            return Err("Not connected".to_string());
+        };
+        let Some(user) = self.current_user() else {
+            return Err("You are not logged in".to_string());
+        };
 
-    let inner = session.clone();
-    ctx.reducers.on_signup(move |ctx, _name, _password| {
-        if let Status::Failed(err) = &ctx.event.status {
-            inner.lock().unwrap().on_login_error(err.to_string());
+        match command {
+            command::Command::Me(action) => {
+                return Ok(Some(format!("*{} {}*", user.profile.display_name, action)));
+            }
+            command::Command::Shrug(text) => {
+                return Ok(Some(format!("{} ¯\\_(ツ)_/¯", text).trim().to_string()));
+            }
+            command::Command::Topic(topic) => {
+                connection.reducers.set_topic(topic).expect("Spacetime error");
+            }
+            command::Command::Nick(name) => {
+                let mut profile = user.profile;
+                profile.display_name = name;
+                connection.reducers.update_profile(profile).expect("Spacetime error");
+            }
+            command::Command::Remind(duration) => {
+                let message = reply.or_else(|| {
+                    connection.db.message().iter().max_by_key(|m| m.sent).map(|m| m.id)
+                });
+                let Some(message) = message else {
+                    return Err("No message to remind about".to_string());
+                };
+
+                let at = Timestamp::now() + TimeDuration::from(duration);
+                connection.reducers.set_reminder(message, at).expect("Spacetime error");
+            }
+            command::Command::Kick(name) => {
+                let Some(target) = connection.db.user().name().find(&name) else {
+                    return Err(format!("User {} not found", name));
+                };
+                connection.reducers.kick_user(target.id).expect("Spacetime error");
+            }
+            command::Command::Bot { name, args } => {
+                if connection.db.slash_command().name().find(&name).is_none() {
+                    return Err(format!("Unknown command /{}", name));
+                }
+                connection
+                    .reducers
+                    .invoke_command(name, args, reply)
+                    .expect("Spacetime error");
+            }
         }
-    });
 
-    let inner = session.clone();
-    let sending_inner = sending.clone();
-    ctx.reducers
-        .on_request_stream(move |ctx, name, size| match &ctx.event.status {
-            Status::Committed => {
-                println!("Sending file {}; {} bytes", name, size);
-                let Some(file) = &mut sending_inner.lock().unwrap().file else {
-                    return;
+        Ok(None)
+    }
+
+    pub fn on_command_error(&mut self, error: String) {
+        self.app.emit("on_command_error", error).expect("Emit error");
+    }
+
+    /// Own pending scheduled messages, nearest first
+    pub fn get_scheduled_messages(&self) -> Vec<ScheduledPayload> {
+        let Some(connection) = &self.connection else {
+            return vec![];
+        };
+
+        let mut messages = connection
+            .db
+            .scheduled_message()
+            .iter()
+            .filter(|m| Some(m.sender) == self.user_id)
+            .filter_map(|m| {
+                let ScheduleAt::Time(at) = m.scheduled_at else {
+                    return None;
                 };
 
-                file.send(inner.clone());
-            }
-            _ => (),
-        });
+                Some(ScheduledPayload {
+                    id: m.scheduled_id,
+                    text: m.text,
+                    reply: m.reply,
+                    at: at.to_duration_since_unix_epoch().unwrap().as_millis(),
+                    has_file: m.file.is_some(),
+                })
+            })
+            .collect::<Vec<_>>();
 
-    let inner = session.clone();
-    let sending_inner = sending.clone();
-    ctx.reducers
-        .on_send_packet(move |ctx, _data: &Vec<u8>| match &ctx.event.status {
-            Status::Committed => {
-                let Some(file) = &mut sending_inner.lock().unwrap().file else {
-                    return;
+        messages.sort_by_key(|m| m.at);
+        messages
+    }
+
+    pub fn on_schedule_error(&mut self, error: String) {
+        self.app.emit("on_schedule_error", error).expect("Emit error");
+    }
+
+    /// Own pending reminders, nearest first
+    pub fn get_reminders(&self) -> Vec<ReminderPayload> {
+        let Some(connection) = &self.connection else {
+            return vec![];
+        };
+
+        let mut reminders = connection
+            .db
+            .reminder()
+            .iter()
+            .filter(|r| Some(r.user_id) == self.user_id)
+            .filter_map(|reminder| {
+                let ScheduleAt::Time(at) = reminder.scheduled_at else {
+                    return None;
                 };
+                let message = connection.db.message().id().find(&reminder.message_id)?;
 
-                let remain = file.send(inner.clone());
-                inner
-                    .lock()
-                    .unwrap()
-                    .on_send_packet(file.size, remain);
-            }
-            _ => (),
+                Some(ReminderPayload {
+                    id: reminder.scheduled_id,
+                    message: MessagePayload::new(message),
+                    at: at.to_duration_since_unix_epoch().unwrap().as_millis(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        reminders.sort_by_key(|r| r.at);
+        reminders
+    }
+
+    /// Fired reminders not yet dismissed, last fired first
+    pub fn get_notifications(&self) -> Vec<NotificationPayload> {
+        let Some(connection) = &self.connection else {
+            return vec![];
+        };
+
+        let mut notifications = connection
+            .db
+            .notification()
+            .iter()
+            .filter(|n| Some(n.user_id) == self.user_id)
+            .filter_map(|notification| {
+                let message = connection.db.message().id().find(&notification.message_id)?;
+
+                Some(NotificationPayload {
+                    id: notification.id,
+                    message: MessagePayload::new(message),
+                    created_at: notification.created_at.to_duration_since_unix_epoch().unwrap().as_millis(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        notifications.sort_by_key(|n| std::cmp::Reverse(n.created_at));
+        notifications
+    }
+
+    pub fn on_bot_error(&mut self, error: String) {
+        self.app.emit("on_bot_error", error).expect("Emit error");
+    }
+
+    pub fn on_reminder_error(&mut self, error: String) {
+        self.app.emit("on_reminder_error", error).expect("Emit error");
+    }
+
+    /// Pinned messages, last pinned first
+    pub fn get_pins(&self) -> Vec<PinPayload> {
+        let Some(connection) = &self.connection else {
+            return vec![];
+        };
+
+        let mut pins = connection.db.pin().iter().collect::<Vec<_>>();
+        pins.sort_by_key(|p| std::cmp::Reverse(p.pinned_at));
+
+        pins.into_iter()
+            .filter_map(|pin| {
+                let message = connection.db.message().id().find(&pin.message_id)?;
+                Some(PinPayload {
+                    message: MessagePayload::new(message),
+                    pinned_by: pin.pinned_by,
+                    pinned_at: pin.pinned_at.to_duration_since_unix_epoch().unwrap().as_millis(),
+                })
+            })
+            .collect()
+    }
+
+    /// Start of `get_messages` page with first unread message
+    pub fn first_unread(&self, page_size: usize) -> Option<usize> {
+        let connection = self.connection.as_ref()?;
+        let first = self.unread_messages().into_iter().next()?;
+
+        let mut messages = connection.db.message().iter().collect::<Vec<_>>();
+        messages.sort_by_key(|m| m.sent);
+
+        let index = messages.iter().position(|m| m.id == first.id)?;
+        Some(index - index % page_size.max(1))
+    }
+
+    /// Emit display names of other users typing in chat
+    pub fn on_typing_changed(&mut self) {
+        let Some(connection) = &self.connection else {
+            return;
+        };
+
+        let names = connection
+            .db
+            .typing()
+            .iter()
+            .filter(|t| Some(t.user_id) != self.user_id)
+            .filter_map(|t| connection.db.user().id().find(&t.user_id))
+            .map(|u| u.profile.display_name)
+            .collect::<Vec<_>>();
+
+        self.app.emit("typing_changed", names).expect("Emit error");
+    }
+
+    /// Online user becomes idle after inactivity
+    pub fn check_idle(&mut self) {
+        if self.idle || self.user_id.is_none() || self.presence != Presence::Online {
+            return;
+        }
+
+        if self.last_activity.elapsed() >= IDLE_AFTER {
+            self.idle = true;
+            self.send_presence(Presence::Idle);
+        }
+    }
+
+    pub fn on_connect_error(&mut self, error: String) {
+        self.connection = None;
+        self.identity = None;
+        self.user_id = None;
+
+        self.app
+            .emit("on_connect_error", error)
+            .expect("Emit error");
+    }
+
+    pub fn on_disconnect(&mut self, error: Option<String>) {
+        self.connection = None;
+        self.identity = None;
+        self.user_id = None;
+
+        self.app.emit("on_disconnect", error).expect("Emit error");
+    }
+
+    pub fn on_login_error(&mut self, error: String) {
+        self.app.emit("on_login_error", error).expect("Emit error");
+    }
+
+    /// Check reducer is not in cooldown after rate limit error
+    pub fn check_cooldown(&self, reducer: &str) -> std::result::Result<(), String> {
+        let left = self
+            .cooldowns
+            .get(reducer)
+            .and_then(|until| until.checked_duration_since(Instant::now()));
+
+        match left {
+            Some(left) => Err(format!("Too many requests, wait {} s", left.as_secs() + 1)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn on_rate_limited(&mut self, reducer: &str, retry_after: u64) {
+        let until = Instant::now() + Duration::from_millis(retry_after);
+        self.cooldowns.insert(reducer.to_string(), until);
+
+        self.app
+            .emit("cooldown", CooldownPayload::new(reducer, retry_after))
+            .expect("Emit error");
+    }
+
+    pub fn on_password_changed(&mut self) {
+        self.app.emit("on_password_changed", ()).expect("Emit error");
+    }
+
+    pub fn on_password_error(&mut self, error: String) {
+        self.app.emit("on_password_error", error).expect("Emit error");
+    }
+
+    pub fn on_profile_error(&mut self, error: String) {
+        self.app.emit("on_profile_error", error).expect("Emit error");
+    }
+
+    pub fn on_account_deleted(&mut self) {
+        self.app.emit("on_account_deleted", ()).expect("Emit error");
+    }
+
+    pub fn on_user_insert(&mut self, user: &User) {
+        if self.user_id == Some(user.id) {
+            self.app
+                .emit("loginned", UserPayload::new(user.clone()))
+                .expect("Emit error");
+        }
+
+        self.app.emit("user_inserted", UserPayload::new(user.clone())).expect("Emit error");
+    }
+
+    pub fn on_user_removed(&mut self, user: &User) {
+        self.app.emit("user_removed", UserPayload::new(user.clone())).expect("Emit error");
+    }
+
+    pub fn on_user_updated(&mut self, _old: &User, new: &User) {
+        if self.user_id == Some(new.id) {
+            self.app
+                .emit("loginned", UserPayload::new(new.clone()))
+                .expect("Emit error");
+        }
+
+        self.app.emit("user_updated", UserPayload::new(new.clone())).expect("Emit error");
+    }
+
+    pub fn on_message_insert(&mut self, message: &Message) {
+        self.app
+            .emit("message_inserted", MessagePayload::new(message.clone()))
+            .expect("Emit error");
+
+        // Old read mentions are not reported on history load
+        if !self.is_read(message) && self.is_mentioned(message) {
+            self.app
+                .emit("mentioned", MessagePayload::new(message.clone()))
+                .expect("Emit error");
+        }
+    }
+
+    /// Queue native notification for new message
+    pub fn notify_message(&mut self, message: &Message) {
+        if self.user_id.is_none() || Some(message.sender) == self.user_id {
+            return;
+        }
+
+        let mentioned = self.is_mentioned(message);
+        let Some(reason) = self.notifier.reason(&self.app, notify::CHAT, &message.text, mentioned) else {
+            return;
+        };
+
+        let Some(connection) = &self.connection else {
+            return;
+        };
+        let sender = connection
+            .db
+            .user()
+            .id()
+            .find(&message.sender)
+            .map_or("-".to_string(), |u| u.profile.display_name);
+
+        let text = match &message.file {
+            Some(file) if message.text.is_empty() => format!("File: {}", file.name),
+            _ => message.text.clone(),
+        };
+
+        self.notifier.push(notify::Pending {
+            message: message.id,
+            sender,
+            text,
+            reason,
         });
+    }
+
+    /// Show native notification for fired reminder
+    pub fn notify_reminder(&mut self, notification: &Notification) {
+        let Some(connection) = &self.connection else {
+            return;
+        };
+        let Some(message) = connection.db.message().id().find(&notification.message_id) else {
+            return;
+        };
+
+        let sender = connection
+            .db
+            .user()
+            .id()
+            .find(&message.sender)
+            .map_or("-".to_string(), |u| u.profile.display_name);
+
+        let text = match &message.file {
+            Some(file) if message.text.is_empty() => format!("File: {}", file.name),
+            _ => message.text,
+        };
+
+        let app = self.app.clone();
+        self.notifier.remind(&app, message.id, &sender, &text);
+    }
+
+    /// Window is focused after notification, show notified message
+    pub fn on_focus_changed(&mut self, focused: bool) {
+        self.notifier.focused = focused;
+        if !focused {
+            return;
+        }
+
+        if let Some(id) = self.notifier.focus.take() {
+            self.app.emit("focus_message", id).expect("Emit error");
+        }
+    }
+
+    fn is_mentioned(&self, message: &Message) -> bool {
+        self.user_id.is_some_and(|id| message.mentions.contains(&id))
+    }
+
+    pub fn on_message_removed(&mut self, message: &Message) {
+        self.app.emit("message_removed", MessagePayload::new(message.clone())).expect("Emit error");
+    }
+
+    pub fn on_message_updated(&mut self, old: &Message, new: &Message) {
+        self.app.emit("message_updated", MessagePayload::new(new.clone())).expect("Emit error");
+
+        // Mention is added by edit
+        if !self.is_mentioned(old) && self.is_mentioned(new) {
+            self.app
+                .emit("mentioned", MessagePayload::new(new.clone()))
+                .expect("Emit error");
+        }
+    }
+
+    /// Load file on inserted
+    pub fn on_file_inserted(&mut self, file: &File) {
+        // File is subscribed not for download (export)
+        let Some(index) = self
+            .downloading
+            .iter()
+            .enumerate()
+            .find(|(_, d)| d.file == file.id)
+            .and_then(|(i, _)| Some(i))
+        else {
+            return;
+        };
+
+        let path = download_path(file.id, &file.name);
+
+        std::fs::create_dir_all(DOWNLOADS_DIR).expect("FS error");
+        std::fs::write(path, &file.data).expect("Write error");
+
+        let state = self.downloading.swap_remove(index);
+        state.subscription.unsubscribe().expect("Spacetime error");
+        self.downloading.retain(|d| d.file != file.id);
+        self.app.emit("on_file_downloaded", file.id).expect("Emit error");
+    }
+
+    pub fn download_file(
+        &mut self,
+        payload: FileRefPayload,
+        inner: SessionState,
+    ) -> Option<String> {
+        let Some(connection) = &self.connection else {
+            return None;
+        };
+
+        // If is downloading...
+        if self.downloading.iter()
+            .find(|d| d.file == payload.id).is_some() {
+            return None;
+        }
+
+        if let Some(path) = file_path(payload.clone()) {
+            return Some(path);
+        }
+        
+        let subscription = connection
+            .subscription_builder()
+            .on_error(move |_ctx, _err| {
+                inner
+                    .lock()
+                    .unwrap()
+                    .downloading
+                    .retain(|d| d.file != payload.id);
+            })
+            .subscribe(format!("SELECT * from file f WHERE f.id = {}", payload.id));
+
+        self.downloading.push(DownloadingFile {
+            file: payload.id,
+            subscription,
+        });
+
+        None
+    }
+
+    /// Export user profile, messages and uploaded files to zip archive
+    pub fn export_my_data(
+        &mut self,
+        path: PathBuf,
+        inner: SessionState,
+    ) -> std::result::Result<(), String> {
+        let Some(connection) = &self.connection else {
+            return Err("Not connected".to_string());
+        };
+
+        if self.exporting.is_some() {
+            return Err("Export is already running".to_string());
+        }
+
+        let Some(user) = self.current_user() else {
+            return Err("You are not logged in".to_string());
+        };
+
+        let mut messages = connection
+            .db
+            .message()
+            .iter()
+            .filter(|m| m.sender == user.id)
+            .collect::<Vec<_>>();
+        messages.sort_by_key(|m| m.sent);
+
+        let files = messages
+            .iter()
+            .filter_map(|m| m.file.as_ref().and_then(|f| Some(f.id)))
+            .collect::<Vec<_>>();
+
+        if files.is_empty() {
+            let result = export::write_user_data(&path, user, messages, Vec::new());
+            self.on_export_finished(path, result);
+            return Ok(());
+        }
+
+        // Files are not cached by client, load them first
+        let queries = files
+            .iter()
+            .map(|id| format!("SELECT * FROM file f WHERE f.id = {}", id))
+            .collect::<Vec<_>>();
+
+        let error_inner = inner.clone();
+        let error_path = path.clone();
+        let subscription = connection
+            .subscription_builder()
+            .on_applied(move |ctx| {
+                let files = files
+                    .iter()
+                    .filter_map(|id| ctx.db.file().id().find(id))
+                    .collect();
+
+                let result = export::write_user_data(&path, user.clone(), messages.clone(), files);
+                inner.lock().unwrap().on_export_finished(path.clone(), result);
+            })
+            .on_error(move |_ctx, err| {
+                error_inner
+                    .lock()
+                    .unwrap()
+                    .on_export_finished(error_path.clone(), Err(err.to_string()));
+            })
+            .subscribe(queries);
+
+        self.exporting = Some(subscription);
+        Ok(())
+    }
+
+    /// Export conversation messages in time range (unix ms) to zip archive,
+    /// attachments missing in download cache are loaded first
+    pub fn export_history(
+        &mut self,
+        path: PathBuf,
+        conversation: String,
+        from: Option<u128>,
+        to: Option<u128>,
+        inner: SessionState,
+    ) -> std::result::Result<(), String> {
+        let Some(connection) = &self.connection else {
+            return Err("Not connected".to_string());
+        };
+
+        if self.exporting.is_some() || self.exporting_history {
+            return Err("Export is already running".to_string());
+        }
+
+        if conversation != notify::CHAT {
+            return Err("Conversation not found".to_string());
+        }
+
+        let in_range = |time: u128| from.is_none_or(|from| time >= from) && to.is_none_or(|to| time <= to);
+        let mut messages = connection
+            .db
+            .message()
+            .iter()
+            .filter(|m| in_range(m.sent.to_duration_since_unix_epoch().unwrap().as_millis()))
+            .collect::<Vec<_>>();
+        messages.sort_by_key(|m| m.sent);
+
+        let mut senders = messages.iter().map(|m| m.sender).collect::<Vec<_>>();
+        senders.sort();
+        senders.dedup();
+
+        let history = export::History {
+            title: "Chat history".to_string(),
+            users: senders.iter().filter_map(|id| connection.db.user().id().find(id)).collect(),
+            avatars: senders.iter().filter_map(|id| connection.db.avatar().user_id().find(id)).collect(),
+            messages,
+        };
+
+        let missing = history
+            .messages
+            .iter()
+            .filter_map(|m| m.file.as_ref())
+            .filter(|f| !download_path(f.id, &f.name).exists())
+            .map(|f| f.id)
+            .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            self.exporting_history = true;
+            spawn_history_export(inner, self.app.clone(), path, history);
+            return Ok(());
+        }
+
+        // Load missing attachments into download cache
+        let queries = missing
+            .iter()
+            .map(|id| format!("SELECT * FROM file f WHERE f.id = {}", id))
+            .collect::<Vec<_>>();
+
+        let error_inner = inner.clone();
+        let error_path = path.clone();
+        let subscription = connection
+            .subscription_builder()
+            .on_applied(move |ctx| {
+                let result = std::fs::create_dir_all(DOWNLOADS_DIR).and_then(|_| {
+                    missing
+                        .iter()
+                        .filter_map(|id| ctx.db.file().id().find(id))
+                        .try_for_each(|file| std::fs::write(download_path(file.id, &file.name), &file.data))
+                });
+
+                match result {
+                    Ok(()) => {
+                        let app = {
+                            let mut session = inner.lock().unwrap();
+                            session.exporting_history = true;
+                            session.app.clone()
+                        };
+                        spawn_history_export(inner, app, path, history);
+                    }
+                    Err(e) => inner.lock().unwrap().on_export_finished(path, Err(e.to_string())),
+                }
+            })
+            .on_error(move |_ctx, err| {
+                error_inner
+                    .lock()
+                    .unwrap()
+                    .on_export_finished(error_path.clone(), Err(err.to_string()));
+            })
+            .subscribe(queries);
+
+        self.exporting = Some(subscription);
+        Ok(())
+    }
+
+    pub fn on_export_finished(&mut self, path: PathBuf, result: std::result::Result<(), String>) {
+        self.exporting_history = false;
+        if let Some(subscription) = self.exporting.take() {
+            if let Err(e) = subscription.unsubscribe() {
+                eprintln!("Unsubscribe error {}", e);
+            }
+        }
+
+        match result {
+            Ok(()) => self.app.emit("on_export_finished", path).expect("Emit error"),
+            Err(e) => self.app.emit("on_export_error", e).expect("Emit error"),
+        }
+    }
+
+    /// Send parsed messages to server in batches
+    pub fn import_messages(&mut self, source: &str, messages: Vec<ImportedMessage>) -> std::result::Result<(), String> {
+        let Some(connection) = &self.connection else {
+            return Err("Not connected".to_string());
+        };
+
+        let batches = import::batches(messages);
+        if batches.is_empty() {
+            return Err("Nothing to import".to_string());
+        }
+
+        self.importing = Some((0, batches.len()));
+        for batch in batches {
+            connection
+                .reducers
+                .import_messages(source.to_string(), batch)
+                .expect("Spacetime error");
+        }
+
+        Ok(())
+    }
+
+    pub fn on_import_batch(&mut self, result: std::result::Result<(), String>) {
+        let Some((done, total)) = self.importing else {
+            return;
+        };
+
+        if let Err(e) = result {
+            self.importing = None;
+            self.app.emit("on_import_error", e).expect("Emit error");
+            return;
+        }
+
+        let done = done + 1;
+        self.app
+            .emit("import_progress", SendPayload::new(done, total))
+            .expect("Emit error");
+
+        if done == total {
+            self.importing = None;
+            self.app.emit("on_import_finished", total).expect("Emit error");
+        } else {
+            self.importing = Some((done, total));
+        }
+    }
+
+    pub fn on_send_packet(&mut self, lenght: usize, remain: usize) {
+        self.app
+            .emit("send_status", SendPayload::new(lenght - remain, lenght))
+            .expect("Emit error");
+    }
+
+    pub fn exit(&self) {
+        println!("Exit");
+
+        let Some(connection) = &self.connection else {
+            return;
+        };
+
+        match connection.disconnect() {
+            Err(e) => eprintln!("Disconnect error {}", e),
+            _ => (),
+        }
+    }
+}
+
+/// Show grouped notifications after rate limit delay
+fn schedule_notifications(inner: SessionState) {
+    let Some(delay) = inner.lock().unwrap().notifier.schedule() else {
+        return;
+    };
+
+    thread::spawn(move || {
+        thread::sleep(delay);
+
+        let mut session = inner.lock().unwrap();
+        let app = session.app.clone();
+        session.notifier.show(&app);
+    });
+}
+
+/// Write history archive in background, emitting progress
+fn spawn_history_export(inner: SessionState, app: AppHandle, path: PathBuf, history: export::History) {
+    thread::spawn(move || {
+        let result = export::write_history(&path, history, |done, total| {
+            app.emit("export_progress", SendPayload::new(done, total)).expect("Emit error");
+        });
+        inner.lock().unwrap().on_export_finished(path, result);
+    });
+}
+
+/// Merge bursts of table changes into single event
+fn debounce(inner: SessionState, event: &'static str) {
+    if !inner.lock().unwrap().debounced.insert(event) {
+        return;
+    }
+
+    thread::spawn(move || {
+        thread::sleep(DEBOUNCE_DELAY);
+
+        let mut session = inner.lock().unwrap();
+        session.debounced.remove(event);
+        match event {
+            "typing_changed" => session.on_typing_changed(),
+            "unread_changed" => session.on_unread_changed(),
+            "receipts_changed" => session
+                .app
+                .emit("receipts_changed", session.get_receipts())
+                .expect("Emit error"),
+            "pins_changed" => session.app.emit("pins_changed", session.get_pins()).expect("Emit error"),
+            "commands_changed" => session
+                .app
+                .emit("commands_changed", session.get_commands())
+                .expect("Emit error"),
+            "topic_changed" => session
+                .app
+                .emit("topic_changed", session.get_topic())
+                .expect("Emit error"),
+            "reminders_changed" => session
+                .app
+                .emit("reminders_changed", session.get_reminders())
+                .expect("Emit error"),
+            "notifications_changed" => session
+                .app
+                .emit("notifications_changed", session.get_notifications())
+                .expect("Emit error"),
+            "scheduled_changed" => session
+                .app
+                .emit("scheduled_changed", session.get_scheduled_messages())
+                .expect("Emit error"),
+            _ => (),
+        }
+    });
+}
+
+fn register_callbacks(ctx: &DbConnection, session: SessionState, sending: SendingFileState) {
+    let inner = session.clone();
+    ctx.db.user().on_insert(move |_ctx, user| {
+        inner.lock().unwrap().on_user_insert(user);
+    });
+
+    let inner = session.clone();
+    ctx.db.message().on_insert(move |ctx, message| {
+        inner.lock().unwrap().on_message_insert(message);
+        debounce(inner.clone(), "unread_changed");
+
+        // Only new messages, not history load
+        if let Event::Reducer(_) = &ctx.event {
+            inner.lock().unwrap().notify_message(message);
+            schedule_notifications(inner.clone());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.db.message().on_update(move |_ctx, old, new| {
+        inner.lock().unwrap().on_message_updated(old, new);
+        debounce(inner.clone(), "unread_changed");
+        debounce(inner.clone(), "pins_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.message().on_delete(move |_ctx, message| {
+        inner.lock().unwrap().on_message_removed(message);
+        debounce(inner.clone(), "unread_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.user().on_update(move |_ctx, old, new| {
+        inner.lock().unwrap().on_user_updated(old, new);
+    });
+
+    let inner = session.clone();
+    ctx.db.user().on_delete(move |_ctx, user| {
+        inner.lock().unwrap().on_user_removed(user);
+    });
+
+    let inner = session.clone();
+    ctx.db.session().on_insert(move |ctx, session| {
+        let user = ctx.db.user().id().find(&session.user_id);
+        inner.lock().unwrap().on_session_insert(session, user);
+        debounce(inner.clone(), "unread_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.session().on_delete(move |_ctx, session| {
+        inner.lock().unwrap().on_session_removed(session);
+    });
+
+    let inner = session.clone();
+    ctx.db.read_state().on_insert(move |_ctx, _state| {
+        debounce(inner.clone(), "unread_changed");
+        debounce(inner.clone(), "receipts_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.read_state().on_update(move |_ctx, _old, _new| {
+        debounce(inner.clone(), "unread_changed");
+        debounce(inner.clone(), "receipts_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.read_state().on_delete(move |_ctx, _state| {
+        debounce(inner.clone(), "receipts_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.pin().on_insert(move |_ctx, _pin| {
+        debounce(inner.clone(), "pins_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.pin().on_delete(move |_ctx, _pin| {
+        debounce(inner.clone(), "pins_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.slash_command().on_insert(move |_ctx, _command| {
+        debounce(inner.clone(), "commands_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.slash_command().on_update(move |_ctx, _old, _new| {
+        debounce(inner.clone(), "commands_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.slash_command().on_delete(move |_ctx, _command| {
+        debounce(inner.clone(), "commands_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.config().on_insert(move |_ctx, _config| {
+        debounce(inner.clone(), "topic_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.config().on_update(move |_ctx, _old, _new| {
+        debounce(inner.clone(), "topic_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.reminder().on_insert(move |_ctx, _reminder| {
+        debounce(inner.clone(), "reminders_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.reminder().on_delete(move |_ctx, _reminder| {
+        debounce(inner.clone(), "reminders_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.notification().on_insert(move |ctx, notification| {
+        // Fired by scheduler, not loaded with subscription
+        if !matches!(ctx.event, Event::SubscribeApplied) {
+            inner.lock().unwrap().notify_reminder(notification);
+        }
+        debounce(inner.clone(), "notifications_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.notification().on_delete(move |_ctx, _notification| {
+        debounce(inner.clone(), "notifications_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.scheduled_message().on_insert(move |_ctx, _message| {
+        debounce(inner.clone(), "scheduled_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.scheduled_message().on_update(move |_ctx, _old, _new| {
+        debounce(inner.clone(), "scheduled_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.scheduled_message().on_delete(move |_ctx, _message| {
+        debounce(inner.clone(), "scheduled_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.typing().on_insert(move |_ctx, _typing| {
+        debounce(inner.clone(), "typing_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.typing().on_delete(move |_ctx, _typing| {
+        debounce(inner.clone(), "typing_changed");
+    });
+
+    let inner = session.clone();
+    ctx.db.file().on_insert(move |_ctx, file| {
+        inner.lock().unwrap().on_file_inserted(file);
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_login(move |ctx, _name, _password| {
+        if let Status::Failed(err) = &ctx.event.status {
+            let session = &mut inner.lock().unwrap();
+            if let Some(delay) = retry_after(err) {
+                session.on_rate_limited("login", delay);
+            }
+            session.on_login_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_signup(move |ctx, _name, _password| {
+        if let Status::Failed(err) = &ctx.event.status {
+            let session = &mut inner.lock().unwrap();
+            if let Some(delay) = retry_after(err) {
+                session.on_rate_limited("signup", delay);
+            }
+            session.on_login_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_send_message(move |ctx, _text, _reply| {
+        if let Status::Failed(err) = &ctx.event.status {
+            if let Some(delay) = retry_after(err) {
+                inner.lock().unwrap().on_rate_limited("send_message", delay);
+            }
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_change_password(move |ctx, _old, _new| match &ctx.event.status {
+        Status::Committed => inner.lock().unwrap().on_password_changed(),
+        Status::Failed(err) => inner.lock().unwrap().on_password_error(err.to_string()),
+        _ => (),
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_update_profile(move |ctx, _profile| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_profile_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_schedule_message(move |ctx, _text, _reply, _at| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_schedule_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_edit_scheduled_message(move |ctx, _id, _text, _at| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_schedule_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_set_reminder(move |ctx, _message_id, _at| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_reminder_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_snooze_notification(move |ctx, _id, _until| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_reminder_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_create_webhook(move |ctx, _bot_id, _name, _token| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_bot_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_delete_webhook(move |ctx, _id| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_bot_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_delete_webhooks(move |ctx, _bot_id| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_bot_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_set_topic(move |ctx, _topic| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_command_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_kick_user(move |ctx, _user_id| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_command_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_invoke_command(move |ctx, _name, _args, _reply| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_command_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_create_bot(move |ctx, _name, _display_name| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_bot_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_issue_bot_token(move |ctx, _bot_id, _token, _scopes| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_bot_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_import_messages(move |ctx, _source, _messages| {
+        let result = match &ctx.event.status {
+            Status::Committed => Ok(()),
+            Status::Failed(err) => Err(err.to_string()),
+            _ => Err("Out of energy".to_string()),
+        };
+        inner.lock().unwrap().on_import_batch(result);
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_set_avatar(move |ctx, _data| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_profile_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_delete_account(move |ctx, _password| match &ctx.event.status {
+        Status::Committed => inner.lock().unwrap().on_account_deleted(),
+        Status::Failed(err) => inner.lock().unwrap().on_password_error(err.to_string()),
+        _ => (),
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_issue_reset_code(move |ctx, _user_id, _code| {
+        if let Status::Failed(err) = &ctx.event.status {
+            inner.lock().unwrap().on_password_error(err.to_string());
+        }
+    });
+
+    let inner = session.clone();
+    ctx.reducers.on_reset_password(move |ctx, _name, _code, _password| match &ctx.event.status {
+        Status::Committed => inner.lock().unwrap().on_password_changed(),
+        Status::Failed(err) => inner.lock().unwrap().on_login_error(err.to_string()),
+        _ => (),
+    });
+
+    let inner = session.clone();
+    let sending_inner = sending.clone();
+    ctx.reducers
+        .on_request_stream(move |ctx, name, size| match &ctx.event.status {
+            Status::Committed => {
+                println!("Sending file {}; {} bytes", name, size);
+                let Some(file) = &mut sending_inner.lock().unwrap().file else {
+                    return;
+                };
+
+                file.send(inner.clone());
+            }
+            _ => (),
+        });
+
+    let inner = session.clone();
+    let sending_inner = sending.clone();
+    ctx.reducers
+        .on_send_packet(move |ctx, data: &Vec<u8>| match &ctx.event.status {
+            Status::Committed => {
+                let Some(file) = &mut sending_inner.lock().unwrap().file else {
+                    return;
+                };
+
+                let remain = file.send(inner.clone());
+                inner
+                    .lock()
+                    .unwrap()
+                    .on_send_packet(file.size, remain);
+            }
+            Status::Failed(err) => {
+                let Some(delay) = retry_after(err) else {
+                    return;
+                };
+                inner.lock().unwrap().on_rate_limited("send_packet", delay);
+
+                // Resend same packet after cooldown
+                let packet = data.clone();
+                let inner = inner.clone();
+                thread::spawn(move || {
+                    thread::sleep(Duration::from_millis(delay));
+                    if let Some(connection) = &inner.lock().unwrap().connection {
+                        connection
+                            .reducers
+                            .send_packet(packet)
+                            .expect("Spacetime error");
+                    }
+                });
+            }
+            _ => (),
+        });
+}
+
+fn on_sub_error(_ctx: &ErrorContext, err: Error) {
+    eprintln!("Subscription failed: {}", err);
+    std::process::exit(1);
+}
+
+fn subscribe_to_tables(ctx: &DbConnection) {
+    ctx.subscription_builder()
+        .on_error(on_sub_error)
+        .subscribe([
+            "SELECT * FROM user",
+            "SELECT * FROM avatar",
+            "SELECT * FROM session",
+            "SELECT * FROM typing",
+            "SELECT * FROM read_state",
+            "SELECT * FROM thumbnail",
+            "SELECT * FROM pin",
+            "SELECT * FROM scheduled_message",
+            "SELECT * FROM reminder",
+            "SELECT * FROM notification",
+            "SELECT * FROM config",
+            "SELECT * FROM slash_command",
+            "SELECT * FROM message",
+            "SELECT * from request r WHERE r.sender = :sender",
+        ]);
+}
+
+fn try_connect(addr: Option<String>, session: SessionState, sending: SendingFileState) {
+    let on_connect_inner = session.clone();
+    let on_disconnect_inner = session.clone();
+
+    let addr = addr.unwrap_or(ADDR.to_string());
+    let uri = get_uri(addr.clone());
+    let token_addr = addr.clone();
+    let res = DbConnection::builder()
+        .on_connect(move |_ctx, identity, token| {
+            on_connect_inner.lock().unwrap().on_connect(identity);
+            println!("Connected!");
+
+            if let Err(e) = creds_store(addr).save(token) {
+                eprintln!("Failed to save credentials: {:?}", e);
+            }
+        })
+        .on_disconnect(move |_ctx, err| {
+            let error = err.and_then(|e| Some(e.to_string()));
+            on_disconnect_inner.lock().unwrap().on_disconnect(error);
+        })
+        .with_token(
+            creds_store(token_addr)
+                .load()
+                .expect("Error loading credentials"),
+        )
+        .with_module_name(DB_NAME.to_string())
+        .with_uri(uri)
+        .build();
+
+    match res {
+        Ok(connection) => {
+            // Setup spacetime callbacks, get tables
+            register_callbacks(&connection, session.clone(), sending);
+            subscribe_to_tables(&connection);
+
+            connection.run_threaded();
+            session.lock().unwrap().connection = Some(connection);
+        }
+        Err(e) => {
+            session.lock().unwrap().on_connect_error(e.to_string());
+        }
+    }
+}
+
+#[tauri::command]
+fn connect(
+    addr: Option<String>,
+    session: State<SessionState>,
+    sending: State<SendingFileState>,
+    connect_handler: State<ConnectionState>,
+) {
+    if let Some(connection) = session.lock().unwrap().connection.take() {
+        match connection.disconnect() {
+            Err(e) => eprintln!("Disconnect error {}", e),
+            _ => (),
+        }
+    }
+
+    // Clear connect handler and join;
+    if let Some(handler) = connect_handler.lock().unwrap().0.take() {
+        match handler.join() {
+            _ => (),
+        }
+    }
+
+    // Connect to STDB in thread
+    let session_inner = session.inner().clone();
+    let sending_inner = sending.inner().clone();
+    let handler = std::thread::spawn(move || try_connect(addr, session_inner, sending_inner));
+
+    connect_handler.lock().unwrap().0 = Some(handler);
+}
+
+#[tauri::command]
+fn disconnect(session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    match connection.disconnect() {
+        _ => (),
+    }
+}
+
+#[tauri::command]
+fn login(name: String, password: String, session: State<SessionState>) {
+    let session = &mut session.lock().unwrap();
+    if let Err(e) = session.check_cooldown("login") {
+        return session.on_login_error(e);
+    }
+
+    let Some(connection) = &session.connection else {
+        return;
+    };
+
+    // Attempt is committed first, failed login keeps its rate limit token
+    connection
+        .reducers
+        .begin_auth("login".to_string(), name.clone(), password.clone())
+        .expect("Spacetime error");
+    connection
+        .reducers
+        .login(name, password)
+        .expect("Spacetime error");
+}
+
+#[tauri::command]
+fn logout(session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection.reducers.logout().expect("Spacetime error");
+}
+
+#[tauri::command]
+fn signup(name: String, password: String, session: State<SessionState>) {
+    let session = &mut session.lock().unwrap();
+    if let Err(e) = session.check_cooldown("signup") {
+        return session.on_login_error(e);
+    }
+
+    let Some(connection) = &session.connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .signup(name, password)
+        .expect("Spacetime error");
+}
+
+#[tauri::command]
+fn change_password(old_password: String, new_password: String, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .change_password(old_password, new_password)
+        .expect("Spacetime error");
+}
+
+/// Issue one-time reset code for user, returns code to pass to the user
+#[tauri::command]
+fn issue_reset_code(user_id: u32, session: State<SessionState>) -> Option<String> {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return None;
+    };
+
+    let code = rand::rng()
+        .sample_iter(rand::distr::Alphanumeric)
+        .take(8)
+        .map(char::from)
+        .collect::<String>()
+        .to_uppercase();
+
+    connection
+        .reducers
+        .issue_reset_code(user_id, code.clone())
+        .expect("Spacetime error");
+
+    Some(code)
+}
+
+#[tauri::command]
+fn create_bot(name: String, display_name: String, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .create_bot(name, display_name)
+        .expect("Spacetime error");
+}
+
+/// Issue new bot token, returns token to pass to the bot once
+#[tauri::command]
+fn issue_bot_token(bot_id: u32, post: bool, session: State<SessionState>) -> Option<String> {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return None;
+    };
+
+    let token = rand::rng()
+        .sample_iter(rand::distr::Alphanumeric)
+        .take(40)
+        .map(char::from)
+        .collect::<String>();
+
+    let scopes = match post {
+        true => vec![BotScope::Post(notify::CHAT.to_string())],
+        false => vec![],
+    };
+
+    connection
+        .reducers
+        .issue_bot_token(bot_id, token.clone(), scopes)
+        .expect("Spacetime error");
+
+    Some(token)
+}
+
+/// Create webhook posting as bot, returns token of hook url
+#[tauri::command]
+fn create_webhook(bot_id: u32, name: String, session: State<SessionState>) -> Option<String> {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return None;
+    };
+
+    let token = rand::rng()
+        .sample_iter(rand::distr::Alphanumeric)
+        .take(40)
+        .map(char::from)
+        .collect::<String>();
+
+    connection
+        .reducers
+        .create_webhook(bot_id, name, token.clone())
+        .expect("Spacetime error");
+
+    Some(token)
+}
+
+#[tauri::command]
+fn delete_webhook(id: u32, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .delete_webhook(id)
+        .expect("Spacetime error");
+}
+
+#[tauri::command]
+fn delete_webhooks(bot_id: u32, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .delete_webhooks(bot_id)
+        .expect("Spacetime error");
+}
+
+#[tauri::command]
+fn revoke_bot_token(bot_id: u32, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .revoke_bot_token(bot_id)
+        .expect("Spacetime error");
+}
+
+#[tauri::command]
+fn reset_password(name: String, code: String, password: String, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .begin_auth("reset_password".to_string(), name.clone(), code.clone())
+        .expect("Spacetime error");
+    connection
+        .reducers
+        .reset_password(name, code, password)
+        .expect("Spacetime error");
+}
+
+#[tauri::command]
+fn set_presence(presence: String, session: State<SessionState>) -> std::result::Result<(), String> {
+    let Some(presence) = parse_presence(&presence) else {
+        return Err(format!("Unknown presence {}", presence));
+    };
+
+    session.lock().unwrap().set_presence(presence);
+    Ok(())
+}
+
+#[tauri::command]
+fn set_typing(session: State<SessionState>) {
+    session.lock().unwrap().set_typing();
+}
+
+#[tauri::command]
+fn report_activity(session: State<SessionState>) {
+    session.lock().unwrap().report_activity();
+}
+
+#[tauri::command]
+fn update_profile(profile: ProfilePayload, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .update_profile(profile.into_profile())
+        .expect("Spacetime error");
+}
+
+#[tauri::command]
+fn delete_account(password: String, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .delete_account(password)
+        .expect("Spacetime error");
 }
 
-fn on_sub_error(_ctx: &ErrorContext, err: Error) {
-    eprintln!("Subscription failed: {}", err);
-    std::process::exit(1);
+#[tauri::command]
+fn export_my_data(path: PathBuf, session: State<SessionState>) -> std::result::Result<(), String> {
+    let inner = session.inner().clone();
+    session.lock().unwrap().export_my_data(path, inner)
 }
 
-fn subscribe_to_tables(ctx: &DbConnection) {
-    ctx.subscription_builder()
-        .on_error(on_sub_error)
-        .subscribe([
-            "SELECT * FROM user",
-            "SELECT * FROM message",
-            "SELECT * from request r WHERE r.sender = :sender",
-        ]);
+#[tauri::command]
+fn export_history(
+    path: PathBuf,
+    conversation: String,
+    from: Option<u128>,
+    to: Option<u128>,
+    session: State<SessionState>,
+) -> std::result::Result<(), String> {
+    let inner = session.inner().clone();
+    session
+        .lock()
+        .unwrap()
+        .export_history(path, conversation, from, to, inner)
 }
 
-fn try_connect(addr: Option<String>, session: SessionState, sending: SendingFileState) {
-    let on_connect_inner = session.clone();
-    let on_disconnect_inner = session.clone();
+/// Import Slack workspace zip or Discord json export, `channel` selects Slack channel
+#[tauri::command]
+fn import_history(
+    path: PathBuf,
+    source: String,
+    channel: Option<String>,
+    session: State<SessionState>,
+) -> std::result::Result<(), String> {
+    if session.lock().unwrap().importing.is_some() {
+        return Err("Import is already running".to_string());
+    }
 
-    let addr = addr.unwrap_or(ADDR.to_string());
-    let uri = get_uri(addr.clone());
-    let token_addr = addr.clone();
-    let res = DbConnection::builder()
-        .on_connect(move |_ctx, identity, token| {
-            on_connect_inner.lock().unwrap().on_connect(identity);
-            println!("Connected!");
+    let inner = session.inner().clone();
+    thread::spawn(move || {
+        let messages = match source.as_str() {
+            "slack" => import::read_slack(&path, channel.as_deref()),
+            "discord" => import::read_discord(&path),
+            _ => Err(format!("Unknown import source {}", source)),
+        };
 
-            if let Err(e) = creds_store(addr).save(token) {
-                eprintln!("Failed to save credentials: {:?}", e);
-            }
-        })
-        .on_disconnect(move |_ctx, err| {
-            let error = err.and_then(|e| Some(e.to_string()));
-            on_disconnect_inner.lock().unwrap().on_disconnect(error);
-        })
-        .with_token(
-            creds_store(token_addr)
-                .load()
-                .expect("Error loading credentials"),
-        )
-        .with_module_name(DB_NAME.to_string())
-        .with_uri(uri)
-        .build();
+        let mut session = inner.lock().unwrap();
+        if let Err(e) = messages.and_then(|messages| session.import_messages(&source, messages)) {
+            session.app.emit("on_import_error", e).expect("Emit error");
+        }
+    });
 
-    match res {
-        Ok(connection) => {
-            // Setup spacetime callbacks, get tables
-            register_callbacks(&connection, session.clone(), sending);
-            subscribe_to_tables(&connection);
+    Ok(())
+}
 
-            connection.run_threaded();
-            session.lock().unwrap().connection = Some(connection);
-        }
-        Err(e) => {
-            session.lock().unwrap().on_connect_error(e.to_string());
-        }
+/// Upload attached file first, then post message with `post`
+fn post_with_upload(
+    connection: &DbConnection,
+    sending: &SendingFileState,
+    attached: Option<String>,
+    post: impl Fn(&RemoteReducers) + Send + 'static,
+) -> std::result::Result<(), String> {
+    if sending.lock().unwrap().file.is_some() {
+        return Err("File is sending".to_string());
     }
+
+    let Some(path) = attached else {
+        post(&connection.reducers);
+        return Ok(());
+    };
+    let file = SendFile::new(path.into())?;
+
+    // On request is finished callback
+    let sending_inner = sending.clone();
+    let reducer = connection.db.request().on_update(move |ctx, _, request| {
+        if request.finished {
+            post(&ctx.reducers);
+
+            sending_inner.lock().unwrap().file = None;
+            let reducer = sending_inner.lock().unwrap().reducer.take().unwrap();
+            ctx.db.request().remove_on_update(reducer);
+        }
+    });
+
+    connection
+        .reducers
+        .request_stream(file.name.clone(), file.size as u64)
+        .expect("Spacetime error");
+
+    sending.lock().unwrap().file = Some(file);
+    sending.lock().unwrap().reducer = Some(reducer);
+
+    Ok(())
 }
 
 #[tauri::command]
-fn connect(
-    addr: Option<String>,
-    session: State<SessionState>,
+fn send_message(
+    text: String,
+    reply: Option<u32>,
+    attached: Option<String>,
     sending: State<SendingFileState>,
-    connect_handler: State<ConnectionState>,
-) {
-    if let Some(connection) = session.lock().unwrap().connection.take() {
-        match connection.disconnect() {
-            Err(e) => eprintln!("Disconnect error {}", e),
-            _ => (),
-        }
-    }
-
-    // Clear connect handler and join;
-    if let Some(handler) = connect_handler.lock().unwrap().0.take() {
-        match handler.join() {
-            _ => (),
-        }
-    }
+    session: State<SessionState>,
+) -> std::result::Result<(), String> {
+    let mut session = session.lock().unwrap();
+    session.check_cooldown("send_message")?;
+
+    let text = match command::parse(&text)? {
+        command::Input::Message(text) => text,
+        command::Input::Command(command) => match session.run_command(command, reply)? {
+            Some(text) => text,
+            None => return Ok(()),
+        },
+    };
 
-    // Connect to STDB in thread
-    let session_inner = session.inner().clone();
-    let sending_inner = sending.inner().clone();
-    let handler = std::thread::spawn(move || try_connect(addr, session_inner, sending_inner));
+    let Some(connection) = &session.connection else {
+        return Err("Not connected".to_string());
+    };
 
-    connect_handler.lock().unwrap().0 = Some(handler);
+    post_with_upload(connection, sending.inner(), attached, move |reducers| {
+        reducers
+            .send_message(text.clone(), reply)
+            .expect("Spacetime error");
+    })
 }
 
 #[tauri::command]
-fn disconnect(session: State<SessionState>) {
+fn schedule_message(
+    text: String,
+    reply: Option<u32>,
+    attached: Option<String>,
+    at: u128,
+    sending: State<SendingFileState>,
+    session: State<SessionState>,
+) -> std::result::Result<(), String> {
     let Some(connection) = &session.lock().unwrap().connection else {
-        return;
+        return Err("Not connected".to_string());
     };
 
-    match connection.disconnect() {
-        _ => (),
-    }
+    let at = Timestamp::from_micros_since_unix_epoch(at as i64 * 1000);
+    post_with_upload(connection, sending.inner(), attached, move |reducers| {
+        reducers
+            .schedule_message(text.clone(), reply, at)
+            .expect("Spacetime error");
+    })
 }
 
 #[tauri::command]
-fn login(name: String, password: String, session: State<SessionState>) {
+fn get_commands(session: State<SessionState>) -> Vec<CommandPayload> {
+    session.lock().unwrap().get_commands()
+}
+
+#[tauri::command]
+fn get_topic(session: State<SessionState>) -> String {
+    session.lock().unwrap().get_topic()
+}
+
+#[tauri::command]
+fn get_scheduled_messages(session: State<SessionState>) -> Vec<ScheduledPayload> {
+    session.lock().unwrap().get_scheduled_messages()
+}
+
+#[tauri::command]
+fn edit_scheduled_message(id: u64, text: String, at: u128, session: State<SessionState>) {
     let Some(connection) = &session.lock().unwrap().connection else {
         return;
     };
 
+    let at = Timestamp::from_micros_since_unix_epoch(at as i64 * 1000);
     connection
         .reducers
-        .login(name, password)
+        .edit_scheduled_message(id, text, at)
         .expect("Spacetime error");
 }
 
 #[tauri::command]
-fn logout(session: State<SessionState>) {
+fn cancel_scheduled_message(id: u64, session: State<SessionState>) {
     let Some(connection) = &session.lock().unwrap().connection else {
         return;
     };
 
-    connection.reducers.logout().expect("Spacetime error");
+    connection
+        .reducers
+        .cancel_scheduled_message(id)
+        .expect("Spacetime error");
 }
 
 #[tauri::command]
-fn signup(name: String, password: String, session: State<SessionState>) {
+fn set_reminder(message_id: u32, at: u128, session: State<SessionState>) {
     let Some(connection) = &session.lock().unwrap().connection else {
         return;
     };
 
+    let at = Timestamp::from_micros_since_unix_epoch(at as i64 * 1000);
     connection
         .reducers
-        .signup(name, password)
+        .set_reminder(message_id, at)
         .expect("Spacetime error");
 }
 
 #[tauri::command]
-fn send_message(
-    text: String,
-    reply: Option<u32>,
-    attached: Option<String>,
-    sending: State<SendingFileState>,
-    session: State<SessionState>,
-) -> std::result::Result<(), String> {
+fn cancel_reminder(id: u64, session: State<SessionState>) {
     let Some(connection) = &session.lock().unwrap().connection else {
-        return Err("Not connected".to_string());
+        return;
     };
 
-    if sending.lock().unwrap().file.is_some() {
-        return Err("File is sending".to_string());
-    }
+    connection
+        .reducers
+        .cancel_reminder(id)
+        .expect("Spacetime error");
+}
 
-    if let Some(path) = attached {
-        let file = SendFile::new(path.into())?;
+#[tauri::command]
+fn get_reminders(session: State<SessionState>) -> Vec<ReminderPayload> {
+    session.lock().unwrap().get_reminders()
+}
 
-        // On request is finished callback
-        let sending_inner = sending.inner().clone();
-        let reducer = connection.db.request().on_update(move |ctx, _, request| {
-            if request.finished {
-                ctx.reducers
-                    .send_message(text.clone(), reply)
-                    .expect("Spacetime error");
+#[tauri::command]
+fn get_notifications(session: State<SessionState>) -> Vec<NotificationPayload> {
+    session.lock().unwrap().get_notifications()
+}
 
-                sending_inner.lock().unwrap().file = None;
-                let reducer = sending_inner.lock().unwrap().reducer.take().unwrap();
-                ctx.db.request().remove_on_update(reducer);
-            }
-        });
+#[tauri::command]
+fn snooze_notification(id: u64, until: u128, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
 
-        connection
-            .reducers
-            .request_stream(file.name.clone(), file.size as u64)
-            .expect("Spacetime error");
+    let until = Timestamp::from_micros_since_unix_epoch(until as i64 * 1000);
+    connection
+        .reducers
+        .snooze_notification(id, until)
+        .expect("Spacetime error");
+}
 
-        sending.lock().unwrap().file = Some(file);
-        sending.lock().unwrap().reducer = Some(reducer);
-    } else {
-        connection
-            .reducers
-            .send_message(text, reply)
-            .expect("Spacetime error");
-    }
+#[tauri::command]
+fn dismiss_notification(id: u64, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
 
-    Ok(())
+    connection
+        .reducers
+        .dismiss_notification(id)
+        .expect("Spacetime error");
 }
 
 #[tauri::command]
@@ -745,16 +2625,88 @@ fn get_messages(session: State<SessionState>, start: usize, end: usize) -> Vec<M
         .collect()
 }
 
+#[tauri::command]
+fn mark_read(message_id: u32, session: State<SessionState>) {
+    session.lock().unwrap().mark_read(message_id);
+}
+
+#[tauri::command]
+fn first_unread(page_size: usize, session: State<SessionState>) -> Option<usize> {
+    session.lock().unwrap().first_unread(page_size)
+}
+
+#[tauri::command]
+fn get_receipts(session: State<SessionState>) -> Vec<ReceiptPayload> {
+    session.lock().unwrap().get_receipts()
+}
+
+#[tauri::command]
+fn get_read_by(message_id: u32, session: State<SessionState>) -> Vec<UserPayload> {
+    session.lock().unwrap().get_read_by(message_id)
+}
+
+#[tauri::command]
+fn read_receipts_enabled(session: State<SessionState>) -> bool {
+    session.lock().unwrap().read_receipts_enabled()
+}
+
+#[tauri::command]
+fn set_read_receipts(enabled: bool, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .set_read_receipts(enabled)
+        .expect("Spacetime error");
+}
+
+#[tauri::command]
+fn get_pins(session: State<SessionState>) -> Vec<PinPayload> {
+    session.lock().unwrap().get_pins()
+}
+
+#[tauri::command]
+fn pin_message(id: u32, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .pin_message(id)
+        .expect("Spacetime error");
+}
+
+#[tauri::command]
+fn unpin_message(id: u32, session: State<SessionState>) {
+    let Some(connection) = &session.lock().unwrap().connection else {
+        return;
+    };
+
+    connection
+        .reducers
+        .unpin_message(id)
+        .expect("Spacetime error");
+}
+
 #[tauri::command]
 fn get_users(session: State<SessionState>) -> Vec<UserPayload> {
     session.lock().unwrap().get_users()
 }
 
+const DOWNLOADS_DIR: &str = "downloads";
+
+fn download_path(id: u32, name: &str) -> PathBuf {
+    PathBuf::from(DOWNLOADS_DIR).join(format!("{}_{}", id, name))
+}
+
 #[tauri::command]
 fn file_path(payload: FileRefPayload) -> Option<String> {
-    let path = format!("downloads\\{}_{}", payload.id, payload.name);
+    let path = download_path(payload.id, &payload.name);
     if std::fs::exists(&path).is_ok_and(|exists| exists) {
-        return Some(path);
+        return Some(path.to_string_lossy().to_string());
     }
 
     return None;
@@ -797,7 +2749,8 @@ fn join_voice_room(session: State<SessionState>, voice: State<VoiceHandlerState>
 pub fn run() {
     let mut builder = tauri::Builder::default()
         .plugin(tauri_plugin_clipboard_manager::init())
-        .plugin(tauri_plugin_dialog::init());
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_notification::init());
     
     #[cfg(desktop)]
     {
@@ -823,6 +2776,14 @@ pub fn run() {
             Ok(())
         })
         .plugin(tauri_plugin_opener::init())
+        .register_asynchronous_uri_scheme_protocol(protocol::AVATAR_SCHEME, |ctx, request, responder| {
+            let app = ctx.app_handle().clone();
+            thread::spawn(move || responder.respond(protocol::avatar(&app, request)));
+        })
+        .register_asynchronous_uri_scheme_protocol(protocol::FILE_SCHEME, |ctx, request, responder| {
+            let app = ctx.app_handle().clone();
+            thread::spawn(move || responder.respond(protocol::file(&app, request)));
+        })
         .manage(Arc::new(Mutex::new(ConnectionHandler::default())))
         .manage(Arc::new(Mutex::new(SendingFile::default())))
         .manage(Arc::new(Mutex::new(VoiceStreamState::default())))
@@ -833,11 +2794,49 @@ pub fn run() {
             signup,
             login,
             logout,
+            change_password,
+            issue_reset_code,
+            create_bot,
+            issue_bot_token,
+            revoke_bot_token,
+            create_webhook,
+            delete_webhook,
+            delete_webhooks,
+            reset_password,
+            set_presence,
+            report_activity,
+            set_typing,
+            update_profile,
+            delete_account,
+            export_my_data,
+            export_history,
+            import_history,
             send_message,
+            schedule_message,
+            get_scheduled_messages,
+            get_commands,
+            get_topic,
+            edit_scheduled_message,
+            cancel_scheduled_message,
+            set_reminder,
+            cancel_reminder,
+            get_reminders,
+            get_notifications,
+            snooze_notification,
+            dismiss_notification,
             edit_message,
             remove_message,
             messages_len,
             get_messages,
+            mark_read,
+            first_unread,
+            get_receipts,
+            get_read_by,
+            read_receipts_enabled,
+            set_read_receipts,
+            get_pins,
+            pin_message,
+            unpin_message,
             get_users,
             file_path,
             download_file,
@@ -850,7 +2849,21 @@ pub fn run() {
     app.run(|handle, event| match event {
         RunEvent::Ready => {
             // Setup session data
-            handle.manage(Arc::new(Mutex::new(SessionInner::new(handle))));
+            let session = Arc::new(Mutex::new(SessionInner::new(handle)));
+            handle.manage(session.clone());
+
+            // Idle presence watcher
+            thread::spawn(move || loop {
+                thread::sleep(Duration::from_secs(15));
+                session.lock().unwrap().check_idle();
+            });
+        },
+        RunEvent::WindowEvent {
+            event: tauri::WindowEvent::Focused(focused),
+            ..
+        } => {
+            let session = handle.state::<SessionState>();
+            session.lock().unwrap().on_focus_changed(focused);
         },
         RunEvent::Exit => {
             let session = handle.state::<SessionState>();
@@ -0,0 +1,199 @@
+use std::{ops::Range, sync::OnceLock};
+
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use syntect::{highlighting::ThemeSet, html::highlighted_html_for_string, parsing::SyntaxSet};
+
+const CODE_THEME: &str = "base16-ocean.dark";
+const SAFE_SCHEMES: [&str; 3] = ["http://", "https://", "mailto:"];
+
+struct Highlighter {
+    syntaxes: SyntaxSet,
+    themes: ThemeSet,
+}
+
+/// Syntax definitions are loaded once, on first code block
+fn highlighter() -> &'static Highlighter {
+    static HIGHLIGHTER: OnceLock<Highlighter> = OnceLock::new();
+    HIGHLIGHTER.get_or_init(|| Highlighter {
+        syntaxes: SyntaxSet::load_defaults_newlines(),
+        themes: ThemeSet::load_defaults(),
+    })
+}
+
+pub fn escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+fn highlight(code: &str, lang: &str) -> String {
+    let highlighter = highlighter();
+    let syntax = highlighter
+        .syntaxes
+        .find_syntax_by_token(lang)
+        .unwrap_or_else(|| highlighter.syntaxes.find_syntax_plain_text());
+
+    let theme = &highlighter.themes.themes[CODE_THEME];
+    highlighted_html_for_string(code, &highlighter.syntaxes, syntax, theme)
+        .unwrap_or_else(|_| format!("<pre>{}</pre>", escape(code)))
+}
+
+/// Escape text at `range` of source, wrapping mention spans
+fn text_html(source: &str, text: &str, range: Range<usize>, mentions: &[Range<usize>]) -> String {
+    // Text is changed by parser (entities, escapes), offsets are not usable
+    if source.get(range.clone()) != Some(text) {
+        return escape(text);
+    }
+
+    let mut result = String::new();
+    let mut position = range.start;
+    for mention in mentions {
+        if mention.start < position || mention.end > range.end {
+            continue;
+        }
+
+        result += &escape(&source[position..mention.start]);
+        result += &format!("<span class=\"mention\">{}</span>", escape(&source[mention.clone()]));
+        position = mention.end;
+    }
+
+    result += &escape(&source[position..range.end]);
+    result
+}
+
+/// Render message markdown subset into safe html:
+/// emphasis, inline code, fenced code with highlighting, links, quotes and lists.
+/// Raw html is escaped, `mentions` are sorted byte ranges of source
+pub fn render(source: &str, mentions: &[Range<usize>]) -> String {
+    let mut html = String::new();
+    // Opened links, unsafe links are rendered as text
+    let mut links = vec![];
+    // Language and text of current code block
+    let mut code: Option<(String, String)> = None;
+
+    for (event, range) in Parser::new_ext(source, Options::empty()).into_offset_iter() {
+        if let Some((_, text)) = &mut code {
+            match event {
+                Event::Text(t) => text.push_str(&t),
+                Event::End(TagEnd::CodeBlock) => {
+                    let (lang, text) = code.take().unwrap();
+                    html += &highlight(&text, &lang);
+                }
+                _ => (),
+            }
+            continue;
+        }
+
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Paragraph | Tag::Heading { .. } => html += "<p>",
+                Tag::Emphasis => html += "<em>",
+                Tag::Strong => html += "<strong>",
+                Tag::BlockQuote(_) => html += "<blockquote>",
+                Tag::List(Some(start)) => html += &format!("<ol start=\"{}\">", start),
+                Tag::List(None) => html += "<ul>",
+                Tag::Item => html += "<li>",
+                Tag::CodeBlock(kind) => {
+                    let lang = match kind {
+                        CodeBlockKind::Fenced(info) => info.split_whitespace().next().unwrap_or("").to_string(),
+                        CodeBlockKind::Indented => String::new(),
+                    };
+                    code = Some((lang, String::new()));
+                }
+                Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                    let safe = SAFE_SCHEMES.iter().any(|s| dest_url.starts_with(s));
+                    if safe {
+                        html += &format!("<a href=\"{}\">", escape(&dest_url));
+                    }
+                    links.push(safe);
+                }
+                _ => (),
+            },
+            Event::End(tag) => match tag {
+                TagEnd::Paragraph | TagEnd::Heading(_) => html += "</p>",
+                TagEnd::Emphasis => html += "</em>",
+                TagEnd::Strong => html += "</strong>",
+                TagEnd::BlockQuote(_) => html += "</blockquote>",
+                TagEnd::List(true) => html += "</ol>",
+                TagEnd::List(false) => html += "</ul>",
+                TagEnd::Item => html += "</li>",
+                TagEnd::Link | TagEnd::Image => {
+                    if links.pop().unwrap_or(false) {
+                        html += "</a>";
+                    }
+                }
+                _ => (),
+            },
+            Event::Text(text) => html += &text_html(source, &text, range, mentions),
+            Event::Code(text) => html += &format!("<code>{}</code>", escape(&text)),
+            Event::Html(text) | Event::InlineHtml(text) => html += &escape(&text),
+            Event::SoftBreak => html += "\n",
+            Event::HardBreak => html += "<br>",
+            Event::Rule => html += "<hr>",
+            _ => (),
+        }
+    }
+
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+
+    #[test]
+    fn escapes_raw_html() {
+        let html = render("<script>alert(1)</script>", &[]);
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;script&gt;"));
+
+        let html = render("hi <img src=x onerror=alert(1)>", &[]);
+        assert!(!html.contains("<img"));
+    }
+
+    #[test]
+    fn drops_unsafe_links() {
+        for source in [
+            "[click](javascript:alert(1))",
+            "[click](JAVASCRIPT:alert(1))",
+            "[click](data:text/html,<script>alert(1)</script>)",
+            "<javascript:alert(1)>",
+            "![img](javascript:alert(1))",
+        ] {
+            let html = render(source, &[]);
+            assert!(!html.contains("<a"), "{}: {}", source, html);
+            assert!(!html.contains("<script"), "{}: {}", source, html);
+        }
+    }
+
+    #[test]
+    fn keeps_safe_links_escaped() {
+        let html = render("[site](https://example.com/?a=1&b=\"x\")", &[]);
+        assert_eq!(html, "<p><a href=\"https://example.com/?a=1&amp;b=&quot;x&quot;\">site</a></p>");
+    }
+
+    #[test]
+    fn escapes_inline_code() {
+        assert_eq!(render("`<b>`", &[]), "<p><code>&lt;b&gt;</code></p>");
+    }
+
+    #[test]
+    fn escapes_code_blocks() {
+        let html = render("```html\n<script>alert(1)</script>\n```", &[]);
+        assert!(!html.contains("<script"));
+    }
+
+    #[test]
+    fn wraps_mentions() {
+        assert_eq!(render("hi @bob", &[3..7]), "<p>hi <span class=\"mention\">@bob</span></p>");
+    }
+}
@@ -0,0 +1,180 @@
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+use tauri_plugin_store::StoreExt;
+
+/// Store with notification settings, shared with frontend
+const SETTINGS_STORE: &str = "user.json";
+/// Conversation id of global chat in mute list
+pub const CHAT: &str = "chat";
+
+/// Wait for more messages to group them
+const GROUP_DELAY: Duration = Duration::from_secs(2);
+/// Min interval between notifications
+const MIN_INTERVAL: Duration = Duration::from_secs(10);
+/// Max senders listed in grouped notification
+const MAX_LISTED: usize = 3;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Reason {
+    Mention,
+    Keyword,
+}
+
+pub struct Pending {
+    pub message: u32,
+    pub sender: String,
+    pub text: String,
+    pub reason: Reason,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct Settings {
+    // Muted conversation ids
+    muted: Vec<String>,
+    // Case-insensitive words to notify on
+    keywords: Vec<String>,
+}
+
+fn load_settings(app: &AppHandle) -> Settings {
+    app.store(SETTINGS_STORE)
+        .ok()
+        .and_then(|store| store.get("notifications"))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+pub struct Notifier {
+    /// Main window focus, updated from window events
+    pub focused: bool,
+    pending: Vec<Pending>,
+    scheduled: bool,
+    last_shown: Option<Instant>,
+    /// Message to show when window is focused
+    pub focus: Option<u32>,
+}
+
+impl Default for Notifier {
+    fn default() -> Self {
+        Self {
+            focused: true,
+            pending: vec![],
+            scheduled: false,
+            last_shown: None,
+            focus: None,
+        }
+    }
+}
+
+impl Notifier {
+    /// Why message in conversation should raise notification, if it should
+    pub fn reason(&self, app: &AppHandle, conversation: &str, text: &str, mentioned: bool) -> Option<Reason> {
+        if self.focused {
+            return None;
+        }
+
+        let settings = load_settings(app);
+        if settings.muted.iter().any(|c| c == conversation) {
+            return None;
+        }
+
+        if mentioned {
+            return Some(Reason::Mention);
+        }
+
+        let text = text.to_lowercase();
+        settings
+            .keywords
+            .iter()
+            .filter(|k| !k.trim().is_empty())
+            .any(|k| text.contains(&k.trim().to_lowercase()))
+            .then_some(Reason::Keyword)
+    }
+
+    pub fn push(&mut self, pending: Pending) {
+        self.pending.push(pending);
+    }
+
+    /// Delay before pending notifications are shown, `None` if already scheduled
+    pub fn schedule(&mut self) -> Option<Duration> {
+        if self.scheduled || self.pending.is_empty() {
+            return None;
+        }
+        self.scheduled = true;
+
+        let cooldown = self
+            .last_shown
+            .map_or(Duration::ZERO, |shown| MIN_INTERVAL.saturating_sub(shown.elapsed()));
+
+        Some(cooldown.max(GROUP_DELAY))
+    }
+
+    /// Show fired reminder immediately, it is requested explicitly
+    pub fn remind(&mut self, app: &AppHandle, message: u32, sender: &str, text: &str) {
+        let result = app
+            .notification()
+            .builder()
+            .title(format!("Reminder: {}", sender))
+            .body(text)
+            .show();
+
+        if let Err(e) = result {
+            eprintln!("Notification error: {}", e);
+        }
+
+        self.focus = Some(message);
+    }
+
+    /// Show pending messages as single notification
+    pub fn show(&mut self, app: &AppHandle) {
+        self.scheduled = false;
+        let pending = std::mem::take(&mut self.pending);
+
+        // Window was focused while waiting
+        let Some(last) = pending.last() else {
+            return;
+        };
+        if self.focused {
+            return;
+        }
+
+        let (title, body) = if pending.len() == 1 {
+            let title = match last.reason {
+                Reason::Mention => format!("{} mentioned you", last.sender),
+                Reason::Keyword => last.sender.clone(),
+            };
+            (title, last.text.clone())
+        } else {
+            let mut senders = pending.iter().map(|p| p.sender.as_str()).collect::<Vec<_>>();
+            senders.sort();
+            senders.dedup();
+
+            let mut body = senders.iter().take(MAX_LISTED).cloned().collect::<Vec<_>>().join(", ");
+            if senders.len() > MAX_LISTED {
+                body += &format!(" and {} more", senders.len() - MAX_LISTED);
+            }
+
+            let mentions = pending.iter().filter(|p| p.reason == Reason::Mention).count();
+            let title = match mentions {
+                0 => format!("{} new messages", pending.len()),
+                n => format!("{} new messages, {} mentions", pending.len(), n),
+            };
+            (title, body)
+        };
+
+        let result = app
+            .notification()
+            .builder()
+            .title(title)
+            .body(body)
+            .show();
+
+        if let Err(e) = result {
+            eprintln!("Notification error: {}", e);
+        }
+
+        self.last_shown = Some(Instant::now());
+        self.focus = Some(last.message);
+    }
+}
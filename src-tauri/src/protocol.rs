@@ -0,0 +1,319 @@
+use std::{
+    collections::HashMap,
+    io::{Read, Seek, SeekFrom},
+    path::Path,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+use tauri::{
+    http::{Request, Response, StatusCode},
+    AppHandle, Manager,
+};
+
+use crate::bindings::*;
+use spacetimedb_sdk::Table;
+use crate::{download_path, FileRefPayload, SessionState};
+
+pub const AVATAR_SCHEME: &str = "chatavatar";
+pub const FILE_SCHEME: &str = "chatfile";
+
+/// Max wait for file downloaded on request
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(120);
+/// Max response size for range request
+const MAX_CHUNK: u64 = 8 * 1024 * 1024;
+
+/// Url of custom scheme resource, windows webview serves schemes over http
+pub fn url(scheme: &str, path: &str) -> String {
+    if cfg!(any(windows, target_os = "android")) {
+        format!("http://{}.localhost/{}", scheme, path)
+    } else {
+        format!("{}://localhost/{}", scheme, path)
+    }
+}
+
+/// Stable avatar url, changes only with avatar content
+pub fn avatar_url(user_id: u32, hash: u64) -> String {
+    url(AVATAR_SCHEME, &format!("{}?v={:x}", user_id, hash))
+}
+
+/// Small avatar for message lists
+pub fn small_avatar_url(user_id: u32, hash: u64) -> String {
+    url(AVATAR_SCHEME, &format!("{}/small?v={:x}", user_id, hash))
+}
+
+pub fn file_url(file_id: u32) -> String {
+    url(FILE_SCHEME, &file_id.to_string())
+}
+
+pub fn thumbnail_url(file_id: u32) -> String {
+    url(FILE_SCHEME, &format!("{}/thumbnail", file_id))
+}
+
+/// Mime type of server encoded image
+fn image_mime(data: &[u8]) -> &'static str {
+    if data.starts_with(b"\x89PNG") {
+        "image/png"
+    } else {
+        "image/jpeg"
+    }
+}
+
+/// Encoded avatars by user and size (small), replaced when hash changes
+#[derive(Default)]
+pub struct AvatarCache(HashMap<(u32, bool), (u64, Arc<Vec<u8>>)>);
+
+impl AvatarCache {
+    pub fn get(&mut self, connection: &DbConnection, user_id: u32, small: bool) -> Option<Arc<Vec<u8>>> {
+        let hash = connection.db.user().id().find(&user_id)?.avatar_hash?;
+        if let Some((cached, data)) = self.0.get(&(user_id, small)) {
+            if *cached == hash {
+                return Some(data.clone());
+            }
+        }
+
+        let avatar = connection.db.avatar().user_id().find(&user_id)?;
+        let data = Arc::new(if small { avatar.small } else { avatar.large });
+        self.0.insert((user_id, small), (avatar.hash, data.clone()));
+        Some(data)
+    }
+}
+
+fn response(status: StatusCode, mime: &str, body: Vec<u8>) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", mime)
+        .header("Access-Control-Allow-Origin", "*")
+        .body(body)
+        .unwrap()
+}
+
+#[derive(Debug, PartialEq)]
+enum ByteRange {
+    // No or malformed header, whole file is sent
+    Full,
+    // Inclusive bounds
+    Partial(u64, u64),
+    // Range is outside of file
+    Unsatisfiable,
+}
+
+/// Parse first range of `Range: bytes=...` header, partial ranges are at most `MAX_CHUNK`
+fn parse_range(header: &str, size: u64) -> ByteRange {
+    let Some(range) = header.strip_prefix("bytes=").and_then(|r| r.split(',').next()) else {
+        return ByteRange::Full;
+    };
+    let Some((start, end)) = range.trim().split_once('-') else {
+        return ByteRange::Full;
+    };
+
+    let (start, end) = if start.is_empty() {
+        // Last `suffix` bytes
+        match end.parse::<u64>() {
+            Ok(0) => return ByteRange::Unsatisfiable,
+            Ok(_) if size == 0 => return ByteRange::Unsatisfiable,
+            Ok(suffix) => (size - suffix.min(size), size - 1),
+            Err(_) => return ByteRange::Full,
+        }
+    } else {
+        match (start.parse::<u64>(), end) {
+            (Ok(start), "") => (start, u64::MAX),
+            (Ok(start), end) => match end.parse::<u64>() {
+                Ok(end) if start <= end => (start, end),
+                _ => return ByteRange::Full,
+            },
+            (Err(_), _) => return ByteRange::Full,
+        }
+    };
+
+    if start >= size {
+        return ByteRange::Unsatisfiable;
+    }
+
+    let end = end.min(size - 1).min(start.saturating_add(MAX_CHUNK - 1));
+    ByteRange::Partial(start, end)
+}
+
+/// Serve file content, partially if range is requested
+fn serve_file(path: &Path, mime: &str, range: Option<&str>) -> Option<Response<Vec<u8>>> {
+    let mut file = std::fs::File::open(path).ok()?;
+    let size = file.metadata().ok()?.len();
+
+    let builder = Response::builder()
+        .header("Content-Type", mime)
+        .header("Accept-Ranges", "bytes")
+        .header("Access-Control-Allow-Origin", "*");
+
+    let (start, end) = match range.map_or(ByteRange::Full, |range| parse_range(range, size)) {
+        ByteRange::Partial(start, end) => (start, end),
+        ByteRange::Unsatisfiable => {
+            return builder
+                .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                .header("Content-Range", format!("bytes */{}", size))
+                .body(Vec::new())
+                .ok();
+        }
+        ByteRange::Full => {
+            let mut body = Vec::with_capacity(size as usize);
+            file.read_to_end(&mut body).ok()?;
+            return builder.status(StatusCode::OK).body(body).ok();
+        }
+    };
+
+    let mut body = Vec::with_capacity((end - start + 1) as usize);
+    file.seek(SeekFrom::Start(start)).ok()?;
+    file.take(end - start + 1).read_to_end(&mut body).ok()?;
+
+    builder
+        .status(StatusCode::PARTIAL_CONTENT)
+        .header("Content-Range", format!("bytes {}-{}/{}", start, end, size))
+        .body(body)
+        .ok()
+}
+
+fn not_found() -> Response<Vec<u8>> {
+    response(StatusCode::NOT_FOUND, "text/plain", Vec::new())
+}
+
+/// `chatavatar://localhost/<user>` and `chatavatar://localhost/<user>/small`
+pub fn avatar(app: &AppHandle, request: Request<Vec<u8>>) -> Response<Vec<u8>> {
+    let path = request.uri().path().trim_matches('/');
+    let (user_id, small) = match path.split_once('/') {
+        None => (path, false),
+        Some((id, "small")) => (id, true),
+        Some(_) => return not_found(),
+    };
+
+    let Ok(user_id) = user_id.parse::<u32>() else {
+        return not_found();
+    };
+    let Some(session) = app.try_state::<SessionState>() else {
+        return not_found();
+    };
+
+    let session = &mut *session.lock().unwrap();
+    let Some(connection) = &session.connection else {
+        return not_found();
+    };
+
+    match session.avatars.get(connection, user_id, small) {
+        Some(data) => response(StatusCode::OK, image_mime(&data), data.to_vec()),
+        None => not_found(),
+    }
+}
+
+/// Download file if it's not cached, waits until download is finished
+fn downloaded_file(session: &SessionState, payload: FileRefPayload) -> Option<std::path::PathBuf> {
+    let path = download_path(payload.id, &payload.name);
+    if path.exists() {
+        return Some(path);
+    }
+
+    let id = payload.id;
+    session.lock().unwrap().download_file(payload, session.clone());
+
+    let started = Instant::now();
+    while session.lock().unwrap().downloading.iter().any(|d| d.file == id) {
+        if started.elapsed() > DOWNLOAD_TIMEOUT {
+            return None;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+
+    path.exists().then_some(path)
+}
+
+/// `chatfile://localhost/<id>` and `chatfile://localhost/<id>/thumbnail`
+pub fn file(app: &AppHandle, request: Request<Vec<u8>>) -> Response<Vec<u8>> {
+    let path = request.uri().path().trim_matches('/');
+    let (id, thumbnail) = match path.split_once('/') {
+        None => (path, false),
+        Some((id, "thumbnail")) => (id, true),
+        Some(_) => return not_found(),
+    };
+
+    let Ok(id) = id.parse::<u32>() else {
+        return not_found();
+    };
+    let Some(session) = app.try_state::<SessionState>() else {
+        return not_found();
+    };
+    let session = session.inner().clone();
+
+    let payload = {
+        let session = session.lock().unwrap();
+        let Some(connection) = &session.connection else {
+            return not_found();
+        };
+
+        if thumbnail {
+            return match connection.db.thumbnail().file_id().find(&id) {
+                Some(thumbnail) => response(StatusCode::OK, "image/jpeg", thumbnail.data),
+                None => not_found(),
+            };
+        }
+
+        connection
+            .db
+            .message()
+            .iter()
+            .find_map(|m| m.file.filter(|f| f.id == id))
+            .map(FileRefPayload::new)
+    };
+
+    let Some(payload) = payload else {
+        return not_found();
+    };
+
+    let mime = payload.mime.clone();
+    let range = request
+        .headers()
+        .get("range")
+        .and_then(|value| value.to_str().ok());
+
+    downloaded_file(&session, payload)
+        .and_then(|path| serve_file(&path, &mime, range))
+        .unwrap_or_else(not_found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_range, ByteRange, MAX_CHUNK};
+
+    #[test]
+    fn parses_ranges() {
+        assert_eq!(parse_range("bytes=0-99", 1000), ByteRange::Partial(0, 99));
+        assert_eq!(parse_range("bytes=500-", 1000), ByteRange::Partial(500, 999));
+        assert_eq!(parse_range("bytes=900-5000", 1000), ByteRange::Partial(900, 999));
+        assert_eq!(parse_range("bytes=10-20, 30-40", 1000), ByteRange::Partial(10, 20));
+    }
+
+    #[test]
+    fn parses_suffix_ranges() {
+        assert_eq!(parse_range("bytes=-100", 1000), ByteRange::Partial(900, 999));
+        assert_eq!(parse_range("bytes=-5000", 1000), ByteRange::Partial(0, 999));
+        assert_eq!(parse_range("bytes=-0", 1000), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range("bytes=-10", 0), ByteRange::Unsatisfiable);
+    }
+
+    #[test]
+    fn rejects_ranges_outside_file() {
+        assert_eq!(parse_range("bytes=1000-", 1000), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range("bytes=2000-3000", 1000), ByteRange::Unsatisfiable);
+        assert_eq!(parse_range("bytes=0-", 0), ByteRange::Unsatisfiable);
+    }
+
+    #[test]
+    fn ignores_malformed_ranges() {
+        for header in ["", "bytes=", "bytes=-", "bytes=abc-", "bytes=5-1", "items=0-1", "bytes=1-x", "bytes=--1"] {
+            assert_eq!(parse_range(header, 1000), ByteRange::Full, "{}", header);
+        }
+    }
+
+    #[test]
+    fn limits_chunk_size() {
+        let size = MAX_CHUNK * 3;
+        assert_eq!(parse_range("bytes=0-", size), ByteRange::Partial(0, MAX_CHUNK - 1));
+        assert_eq!(parse_range(&format!("bytes={}-", u64::MAX - 1), u64::MAX), ByteRange::Partial(u64::MAX - 1, u64::MAX - 1));
+    }
+}
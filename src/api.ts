@@ -1,10 +1,38 @@
+interface StatusPayload {
+  text: string,
+  emoji: string | null,
+  // unix ms
+  expires: number | null
+}
+
+interface ProfilePayload {
+  display_name: string,
+  status: StatusPayload | null,
+  bio: string,
+  pronouns: string,
+  timezone: string
+}
+
 interface UserPayload {
   id: number,
   name: string,
+  display_name: string,
   // base64 string
   avatar: string | null,
+  // 64 px variant for message lists
+  avatar_small: string | null,
   is_admin: boolean,
-  online: boolean
+  is_bot: boolean,
+  online: boolean,
+  // offline | online | idle | dnd | invisible
+  presence: string,
+  // unix ms
+  last_seen: number | null,
+  deleted: boolean,
+  status: StatusPayload | null,
+  bio: string,
+  pronouns: string,
+  timezone: string
 }
 
 interface MessagePayload {
@@ -14,13 +42,86 @@ interface MessagePayload {
   edited: number | null,
   reply: number | null,
   text: string,
-  file: FileRefPayload | null
+  // Rendered markdown
+  html: string,
+  file: FileRefPayload | null,
+  mentions: MentionPayload[]
+}
+
+interface MentionPayload {
+  // user | here | everyone
+  kind: string,
+  user_id: number | null,
+  // UTF-16 offsets in text
+  start: number,
+  end: number
+}
+
+interface PinPayload {
+  message: MessagePayload,
+  pinned_by: number,
+  // unix ms
+  pinned_at: number
+}
+
+interface CommandPayload {
+  name: string,
+  hint: string,
+  description: string,
+  // Display name of bot handling command, built-in if null
+  bot: string | null
+}
+
+interface ScheduledPayload {
+  id: number,
+  text: string,
+  reply: number | null,
+  // unix ms
+  at: number,
+  has_file: boolean
+}
+
+interface ReminderPayload {
+  id: number,
+  message: MessagePayload,
+  // unix ms
+  at: number
+}
+
+interface NotificationPayload {
+  id: number,
+  message: MessagePayload,
+  // unix ms
+  created_at: number
 }
 
 interface FileRefPayload {
   id: number,
   name: string,
-  size: number
+  size: number,
+  mime: string,
+  // Image dimensions
+  width: number | null,
+  height: number | null,
+  // Custom scheme urls
+  url: string,
+  thumbnail: string | null
+}
+
+interface CooldownPayload {
+  reducer: string,
+  retry_after: number
+}
+
+interface UnreadPayload {
+  first: number | null,
+  unread: number,
+  mentions: number
+}
+
+interface ReceiptPayload {
+  user: UserPayload,
+  read_until: number
 }
 
 interface SendPayload {
@@ -37,9 +138,19 @@ function sender(users: Map<number, UserPayload>, message: MessagePayload): UserP
       let unknow: UserPayload = {
         id: -1,
         name: "-",
+        display_name: "-",
         avatar: null,
+        avatar_small: null,
         is_admin: false,
-        online: false
+        is_bot: false,
+        online: false,
+        presence: "offline",
+        last_seen: null,
+        deleted: false,
+        status: null,
+        bio: "",
+        pronouns: "",
+        timezone: ""
       };
 
       return unknow
@@ -59,9 +170,10 @@ function getMesssage(messages: Map<number, MessagePayload>, id: number | null):
 
 function avatarName(user: UserPayload | undefined): string | undefined {
   if (!user) { return undefined };
+  if (user.deleted) { return "-" };
 
-  return user.name.substring(0, 2)
+  return user.display_name.substring(0, 2)
 }
 
 export { sender, getMesssage, messagesChunk, avatarName }
-export type { UserPayload, MessagePayload, FileRefPayload, SendPayload }
\ No newline at end of file
+export type { UserPayload, StatusPayload, ProfilePayload, MessagePayload, MentionPayload, FileRefPayload, SendPayload, CooldownPayload, UnreadPayload, ReceiptPayload, PinPayload, ScheduledPayload, ReminderPayload, NotificationPayload, CommandPayload }
\ No newline at end of file
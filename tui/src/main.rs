@@ -0,0 +1,405 @@
+//! Terminal chat client: `cargo run -- [address]`
+//!
+//! Keys: Enter sends, Up/Down select message, PageUp/PageDown scroll,
+//! Ctrl-R reply, Ctrl-E edit, Ctrl-D delete, Ctrl-S download attachment,
+//! Esc clears selection, Ctrl-C quits.
+#[path = "../../src-tauri/src/bindings/mod.rs"]
+mod bindings;
+mod ui;
+
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bindings::*;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use spacetimedb_sdk::{credentials, DbContext, Status, SubscriptionHandle as _, Table};
+
+/// STDB connection consts
+const ADDR: &str = "localhost";
+const DB_NAME: &str = "chat";
+/// Same directory as desktop client
+const DOWNLOADS_DIR: &str = "downloads";
+/// Messages moved by page keys
+const PAGE_SIZE: isize = 10;
+
+/// Load saved user credentials, shared with desktop client
+fn creds_store(addr: String) -> credentials::File {
+    credentials::File::new(format!("delfi-chat-{}", addr))
+}
+
+fn download_path(id: u32, name: &str) -> PathBuf {
+    PathBuf::from(DOWNLOADS_DIR).join(format!("{}_{}", id, name))
+}
+
+/// State changed by connection callbacks
+#[derive(Default)]
+pub struct State {
+    pub connected: bool,
+    // Last error or notice, shown in status line
+    pub status: String,
+    downloading: Vec<(u32, SubscriptionHandle)>,
+}
+type SharedState = Arc<Mutex<State>>;
+
+impl State {
+    fn on_error(&mut self, status: &Status) {
+        match status {
+            Status::Committed => (),
+            Status::Failed(err) => self.status = err.to_string(),
+            _ => self.status = "Out of energy".to_string(),
+        }
+    }
+
+    fn on_file_inserted(&mut self, file: &File) {
+        let Some(index) = self.downloading.iter().position(|(id, _)| *id == file.id) else {
+            return;
+        };
+
+        let path = download_path(file.id, &file.name);
+        let result = std::fs::create_dir_all(DOWNLOADS_DIR).and_then(|_| std::fs::write(&path, &file.data));
+        self.status = match result {
+            Ok(()) => format!("Saved to {}", path.display()),
+            Err(e) => format!("Download failed: {}", e),
+        };
+
+        let (_, subscription) = self.downloading.swap_remove(index);
+        subscription.unsubscribe().ok();
+    }
+}
+
+#[derive(PartialEq)]
+pub enum Field {
+    Name,
+    Password,
+}
+
+pub struct App {
+    pub connection: DbConnection,
+    pub state: SharedState,
+    // Login form
+    pub field: Field,
+    pub name: String,
+    pub password: String,
+    // Message box
+    pub input: String,
+    // Selected message, history is scrolled to it
+    pub selected: Option<u32>,
+    pub reply: Option<u32>,
+    pub editing: Option<u32>,
+    quit: bool,
+}
+
+impl App {
+    /// Own session is opened on login
+    pub fn user_id(&self) -> Option<u32> {
+        let identity = self.connection.try_identity()?;
+        self.connection.db.session().identity().find(&identity).map(|s| s.user_id)
+    }
+
+    pub fn user_name(&self, id: u32) -> String {
+        self.connection
+            .db
+            .user()
+            .id()
+            .find(&id)
+            .map_or("Unknown".to_string(), |u| u.profile.display_name)
+    }
+
+    pub fn topic(&self) -> String {
+        self.connection.db.config().iter().next().map(|c| c.topic).unwrap_or_default()
+    }
+
+    /// Messages from oldest to newest
+    pub fn messages(&self) -> Vec<Message> {
+        let mut messages = self.connection.db.message().iter().collect::<Vec<_>>();
+        messages.sort_by_key(|m| (m.sent, m.id));
+        messages
+    }
+
+    fn selected_message(&self) -> Option<Message> {
+        self.connection.db.message().id().find(&self.selected?)
+    }
+
+    fn set_status(&self, status: &str) {
+        self.state.lock().unwrap().status = status.to_string();
+    }
+
+    /// Move selection by `offset` messages, past newest clears it
+    fn select(&mut self, offset: isize) {
+        let ids = self.messages().iter().map(|m| m.id).collect::<Vec<_>>();
+        let current = self
+            .selected
+            .and_then(|id| ids.iter().position(|i| *i == id))
+            .unwrap_or(ids.len());
+
+        let index = (current as isize + offset).clamp(0, ids.len() as isize) as usize;
+        self.selected = ids.get(index).copied();
+    }
+
+    fn clear(&mut self) {
+        if self.editing.take().is_some() {
+            self.input.clear();
+        }
+        self.selected = None;
+        self.reply = None;
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+
+        if self.user_id().is_some() {
+            self.on_chat_key(key);
+        } else {
+            self.on_login_key(key);
+        }
+    }
+
+    fn on_login_key(&mut self, key: KeyEvent) {
+        let value = match self.field {
+            Field::Name => &mut self.name,
+            Field::Password => &mut self.password,
+        };
+
+        match key.code {
+            KeyCode::Tab | KeyCode::Up | KeyCode::Down => {
+                self.field = match self.field {
+                    Field::Name => Field::Password,
+                    Field::Password => Field::Name,
+                };
+            }
+            KeyCode::Char(c) => value.push(c),
+            KeyCode::Backspace => {
+                value.pop();
+            }
+            KeyCode::Enter => {
+                if self.name.is_empty() || self.password.is_empty() {
+                    return self.set_status("Enter name and password");
+                }
+                self.set_status("Logging in...");
+                let password = std::mem::take(&mut self.password);
+                self.connection
+                    .reducers
+                    .begin_auth("login".to_string(), self.name.clone(), password.clone())
+                    .expect("Spacetime error");
+                self.connection
+                    .reducers
+                    .login(self.name.clone(), password)
+                    .expect("Spacetime error");
+            }
+            _ => (),
+        }
+    }
+
+    fn on_chat_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            match key.code {
+                KeyCode::Char('r') => self.reply = self.selected,
+                KeyCode::Char('e') => self.edit_selected(),
+                KeyCode::Char('d') => self.remove_selected(),
+                KeyCode::Char('s') => self.download_selected(),
+                _ => (),
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Up => self.select(-1),
+            KeyCode::Down => self.select(1),
+            KeyCode::PageUp => self.select(-PAGE_SIZE),
+            KeyCode::PageDown => self.select(PAGE_SIZE),
+            KeyCode::Esc => self.clear(),
+            KeyCode::Char(c) => self.input.push(c),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Enter => self.send(),
+            _ => (),
+        }
+    }
+
+    fn send(&mut self) {
+        let text = self.input.trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+
+        let reducers = &self.connection.reducers;
+        let result = match self.editing.take() {
+            Some(id) => reducers.edit_message(id, text),
+            None => reducers.send_message(text, self.reply.take()),
+        };
+        result.expect("Spacetime error");
+
+        self.input.clear();
+        self.selected = None;
+    }
+
+    fn edit_selected(&mut self) {
+        let Some(message) = self.selected_message() else {
+            return;
+        };
+
+        if Some(message.sender) != self.user_id() {
+            return self.set_status("You can edit only your messages");
+        }
+
+        self.reply = None;
+        self.editing = Some(message.id);
+        self.input = message.text;
+    }
+
+    fn remove_selected(&mut self) {
+        let Some(message) = self.selected_message() else {
+            return;
+        };
+
+        self.connection
+            .reducers
+            .remove_message(message.id)
+            .expect("Spacetime error");
+        self.selected = None;
+    }
+
+    fn download_selected(&mut self) {
+        let Some(file) = self.selected_message().and_then(|m| m.file) else {
+            return self.set_status("Message has no attachment");
+        };
+
+        let path = download_path(file.id, &file.name);
+        if std::fs::exists(&path).is_ok_and(|exists| exists) {
+            return self.set_status(&format!("Saved to {}", path.display()));
+        }
+
+        let mut state = self.state.lock().unwrap();
+        if state.downloading.iter().any(|(id, _)| *id == file.id) {
+            return;
+        }
+
+        let inner = self.state.clone();
+        let id = file.id;
+        let subscription = self
+            .connection
+            .subscription_builder()
+            .on_error(move |_ctx, err| {
+                let state = &mut inner.lock().unwrap();
+                state.downloading.retain(|(file, _)| *file != id);
+                state.status = format!("Download failed: {}", err);
+            })
+            .subscribe(format!("SELECT * from file f WHERE f.id = {}", id));
+
+        state.downloading.push((id, subscription));
+        state.status = format!("Downloading {}...", file.name);
+    }
+}
+
+fn connect(addr: String, state: SharedState) -> DbConnection {
+    let on_connect_inner = state.clone();
+    let on_disconnect_inner = state.clone();
+
+    let token_addr = addr.clone();
+    let connection = DbConnection::builder()
+        .on_connect(move |_ctx, _identity, token| {
+            on_connect_inner.lock().unwrap().connected = true;
+
+            if let Err(e) = creds_store(addr).save(token) {
+                on_connect_inner.lock().unwrap().status = format!("Failed to save credentials: {:?}", e);
+            }
+        })
+        .on_disconnect(move |_ctx, err| {
+            let state = &mut on_disconnect_inner.lock().unwrap();
+            state.connected = false;
+            state.status = format!("Disconnected: {}", err.map_or("closed".to_string(), |e| e.to_string()));
+        })
+        .with_token(
+            creds_store(token_addr.clone())
+                .load()
+                .expect("Error loading credentials"),
+        )
+        .with_module_name(DB_NAME)
+        .with_uri(format!("http://{}:3000", token_addr))
+        .build()
+        .expect("Connection error");
+
+    let inner = state.clone();
+    connection.reducers.on_login(move |ctx, _name, _password| {
+        let state = &mut inner.lock().unwrap();
+        state.status.clear();
+        state.on_error(&ctx.event.status);
+    });
+
+    let inner = state.clone();
+    connection.reducers.on_send_message(move |ctx, _text, _reply| {
+        inner.lock().unwrap().on_error(&ctx.event.status);
+    });
+
+    let inner = state.clone();
+    connection.reducers.on_edit_message(move |ctx, _id, _text| {
+        inner.lock().unwrap().on_error(&ctx.event.status);
+    });
+
+    let inner = state.clone();
+    connection.reducers.on_remove_message(move |ctx, _id| {
+        inner.lock().unwrap().on_error(&ctx.event.status);
+    });
+
+    let inner = state;
+    connection.db.file().on_insert(move |_ctx, file| {
+        inner.lock().unwrap().on_file_inserted(file);
+    });
+
+    connection
+        .subscription_builder()
+        .on_error(|_ctx, err| eprintln!("Subscription failed: {}", err))
+        .subscribe([
+            "SELECT * FROM user",
+            "SELECT * FROM session",
+            "SELECT * FROM config",
+            "SELECT * FROM message",
+        ]);
+
+    connection.run_threaded();
+    connection
+}
+
+fn main() {
+    let addr = std::env::args().nth(1).unwrap_or(ADDR.to_string());
+
+    let state = SharedState::default();
+    let connection = connect(addr, state.clone());
+
+    let mut app = App {
+        connection,
+        state,
+        field: Field::Name,
+        name: String::new(),
+        password: String::new(),
+        input: String::new(),
+        selected: None,
+        reply: None,
+        editing: None,
+        quit: false,
+    };
+
+    let mut terminal = ratatui::init();
+    while !app.quit {
+        terminal.draw(|frame| ui::draw(frame, &app)).expect("Draw error");
+
+        // Redraw regularly to show updates from server
+        if event::poll(Duration::from_millis(100)).expect("Terminal error") {
+            if let Event::Key(key) = event::read().expect("Terminal error") {
+                if key.kind == KeyEventKind::Press {
+                    app.on_key(key);
+                }
+            }
+        }
+    }
+    ratatui::restore();
+
+    app.connection.disconnect().ok();
+}
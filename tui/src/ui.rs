@@ -0,0 +1,187 @@
+use ratatui::{
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+    Frame,
+};
+
+use crate::{bindings::*, App, Field};
+
+const KEYS_HINT: &str = " Enter send · ↑↓ select · ^R reply · ^E edit · ^D delete · ^S download · Esc clear ";
+
+/// `HH:MM` in UTC
+fn format_time(message: &Message) -> String {
+    let minutes = message.sent.to_micros_since_unix_epoch() / 60_000_000;
+    let minutes = minutes.rem_euclid(24 * 60);
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Split line to rows of at most `width` characters, on spaces when possible
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    let mut rows = Vec::new();
+    let mut row = String::new();
+    for word in line.split(' ') {
+        let mut word = word.to_string();
+        loop {
+            let (row_len, word_len) = (row.chars().count(), word.chars().count());
+            if row_len > 0 && row_len + 1 + word_len <= width {
+                row = format!("{} {}", row, word);
+                break;
+            }
+            if row_len > 0 {
+                rows.push(std::mem::take(&mut row));
+            }
+            if word_len <= width {
+                row = word;
+                break;
+            }
+
+            // Word is longer than row
+            let split = word.char_indices().nth(width).map_or(word.len(), |(i, _)| i);
+            rows.push(word[..split].to_string());
+            word = word[split..].to_string();
+        }
+    }
+    rows.push(row);
+    rows
+}
+
+fn message_lines(app: &App, message: &Message, width: usize) -> Vec<Line<'static>> {
+    let muted = Style::new().fg(Color::DarkGray);
+
+    let mut header = vec![
+        Span::styled(format_time(message), muted),
+        Span::raw(" "),
+        Span::styled(app.user_name(message.sender), Style::new().fg(Color::Cyan).bold()),
+    ];
+    if message.edited.is_some() {
+        header.push(Span::styled(" (edited)", muted));
+    }
+    let mut lines = vec![Line::from(header)];
+
+    if let Some(reply) = message.reply {
+        let text = match app.connection.db.message().id().find(&reply) {
+            Some(replied) => format!(
+                "↳ {}: {}",
+                app.user_name(replied.sender),
+                replied.text.lines().next().unwrap_or_default()
+            ),
+            None => "↳ deleted message".to_string(),
+        };
+        let text = text.chars().take(width).collect::<String>();
+        lines.push(Line::styled(text, muted.italic()));
+    }
+
+    for line in message.text.lines() {
+        lines.extend(wrap(line, width).into_iter().map(Line::raw));
+    }
+
+    if let Some(file) = &message.file {
+        let text = format!("📎 {} ({} KB)", file.name, file.size.div_ceil(1024));
+        lines.push(Line::styled(text, Style::new().fg(Color::Yellow)));
+    }
+
+    lines
+}
+
+fn draw_login(frame: &mut Frame, app: &App, area: Rect) {
+    let [area] = Layout::vertical([Constraint::Length(6)]).flex(Flex::Center).areas(area);
+    let [area] = Layout::horizontal([Constraint::Length(40)]).flex(Flex::Center).areas(area);
+
+    let field = |label: &str, value: String, active: bool| {
+        let style = if active { Style::new().fg(Color::Cyan) } else { Style::new() };
+        Line::from(vec![Span::styled(format!("{:>10}: ", label), style), Span::raw(value)])
+    };
+
+    let lines = vec![
+        Line::raw(""),
+        field("Name", app.name.clone(), app.field == Field::Name),
+        field("Password", "*".repeat(app.password.chars().count()), app.field == Field::Password),
+    ];
+
+    let block = Block::bordered()
+        .title(" Login ")
+        .title_bottom(" Tab switch · Enter login · ^C quit ");
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_chat(frame: &mut Frame, app: &App, area: Rect) {
+    let [history, input] = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(area);
+
+    // Messages are laid out from selected or newest one upwards
+    let width = history.width.saturating_sub(4) as usize;
+    let height = history.height.saturating_sub(2) as usize;
+    let messages = app.messages();
+    let end = app
+        .selected
+        .and_then(|id| messages.iter().position(|m| m.id == id))
+        .map_or(messages.len(), |i| i + 1);
+
+    let mut lines = Vec::new();
+    for message in messages[..end].iter().rev() {
+        let marker = if Some(message.id) == app.selected {
+            Span::styled("▌ ", Style::new().fg(Color::Yellow))
+        } else {
+            Span::raw("  ")
+        };
+
+        let rows = message_lines(app, message, width).into_iter().map(|line| {
+            let mut spans = vec![marker.clone()];
+            spans.extend(line.spans);
+            Line::from(spans).style(line.style)
+        });
+        lines.extend(rows.rev());
+
+        if lines.len() >= height {
+            break;
+        }
+    }
+    lines.truncate(height);
+    lines.reverse();
+
+    let topic = app.topic();
+    let title = if topic.is_empty() {
+        " chat ".to_string()
+    } else {
+        format!(" chat · {} ", topic)
+    };
+    frame.render_widget(Paragraph::new(lines).block(Block::bordered().title(title)), history);
+
+    let title = match (app.editing, app.reply) {
+        (Some(_), _) => " Editing message ".to_string(),
+        (None, Some(reply)) => match app.connection.db.message().id().find(&reply) {
+            Some(message) => format!(" Reply to {} ", app.user_name(message.sender)),
+            None => " Reply to deleted message ".to_string(),
+        },
+        (None, None) => " Message ".to_string(),
+    };
+
+    // Keep end of long input visible
+    let visible = input.width.saturating_sub(3) as usize;
+    let skip = app.input.chars().count().saturating_sub(visible);
+    let text = app.input.chars().skip(skip).collect::<String>();
+
+    let cursor = (input.x + 1 + text.chars().count() as u16, input.y + 1);
+    let block = Block::bordered().title(title).title_bottom(KEYS_HINT);
+    frame.render_widget(Paragraph::new(text).block(block), input);
+    frame.set_cursor_position(cursor);
+}
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [main, status] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+    if app.user_id().is_some() {
+        draw_chat(frame, app, main);
+    } else {
+        draw_login(frame, app, main);
+    }
+
+    let state = app.state.lock().unwrap();
+    let text = if !state.connected && state.status.is_empty() {
+        "Connecting...".to_string()
+    } else {
+        state.status.clone()
+    };
+    frame.render_widget(Paragraph::new(text).fg(Color::DarkGray), status);
+}